@@ -0,0 +1,119 @@
+//! Criterion benchmarks of the dB/voltage conversions.
+//!
+//! Run with `cargo bench --bench conversions`. Each conversion is measured over several input
+//! distributions, since the cost of the clamping shortcuts and the binary search depends on
+//! where the inputs fall. The inputs are shuffled with a fixed seed, like in the examples.
+
+use audio_utils::{db_to_volt, db_to_volt_f, db_to_volt_slice, volt_to_db, volt_to_db_f};
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use rand::{Rng, SeedableRng, rngs::SmallRng};
+use std::hint::black_box;
+
+/// Number of inputs per iteration
+const INPUTS: usize = 1024;
+
+fn rng() -> SmallRng {
+    SmallRng::seed_from_u64(0xDEC1_BA5E)
+}
+
+/// Integer dB inputs: within the table, and partially outside where clamping kicks in.
+fn db_inputs() -> Vec<(&'static str, Vec<i32>)> {
+    let mut rng = rng();
+    vec![
+        ("in_range", (0..INPUTS).map(|_| rng.random_range(-100..=27)).collect()),
+        ("clamped", (0..INPUTS).map(|_| rng.random_range(-200..=100)).collect()),
+    ]
+}
+
+/// Fractional dB inputs within the table.
+fn db_f_inputs() -> Vec<(&'static str, Vec<f32>)> {
+    let mut rng = rng();
+    vec![
+        ("in_range", (0..INPUTS).map(|_| rng.random_range(-100.0..27.0)).collect()),
+        ("clamped", (0..INPUTS).map(|_| rng.random_range(-200.0..100.0)).collect()),
+    ]
+}
+
+/// Gain inputs: uniform in dB (every table region equally often), uniform in the linear
+/// domain (mostly loud values), and partially outside the table.
+fn gain_inputs() -> Vec<(&'static str, Vec<f32>)> {
+    let mut rng = rng();
+    vec![
+        (
+            "log_uniform",
+            (0..INPUTS).map(|_| 10f32.powf(rng.random_range(-100.0..27.0) / 20.0)).collect(),
+        ),
+        ("linear_uniform", (0..INPUTS).map(|_| rng.random_range(0.0..1.0)).collect()),
+        (
+            "clamped",
+            (0..INPUTS).map(|_| 10f32.powf(rng.random_range(-200.0..100.0) / 20.0)).collect(),
+        ),
+    ]
+}
+
+fn bench_db_to_volt(c: &mut Criterion) {
+    let mut group = c.benchmark_group("db_to_volt");
+    group.throughput(Throughput::Elements(INPUTS as u64));
+    for (name, dbs) in db_inputs() {
+        group.bench_with_input(BenchmarkId::new("lookup", name), &dbs, |b, dbs| {
+            b.iter(|| {
+                for &db in dbs {
+                    black_box(db_to_volt(black_box(db)));
+                }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("slice", name), &dbs, |b, dbs| {
+            let mut out = vec![0.0; dbs.len()];
+            b.iter(|| db_to_volt_slice(black_box(dbs), black_box(&mut out)))
+        });
+        group.bench_with_input(BenchmarkId::new("powf", name), &dbs, |b, dbs| {
+            b.iter(|| {
+                for &db in dbs {
+                    black_box(10.0_f32.powf(black_box(db) as f32 / 20.0));
+                }
+            })
+        });
+    }
+    for (name, dbs) in db_f_inputs() {
+        group.bench_with_input(BenchmarkId::new("interpolated", name), &dbs, |b, dbs| {
+            b.iter(|| {
+                for &db in dbs {
+                    black_box(db_to_volt_f(black_box(db)));
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_volt_to_db(c: &mut Criterion) {
+    let mut group = c.benchmark_group("volt_to_db");
+    group.throughput(Throughput::Elements(INPUTS as u64));
+    for (name, gains) in gain_inputs() {
+        group.bench_with_input(BenchmarkId::new("lookup", name), &gains, |b, gains| {
+            b.iter(|| {
+                for &gain in gains {
+                    black_box(volt_to_db(black_box(gain)));
+                }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("interpolated", name), &gains, |b, gains| {
+            b.iter(|| {
+                for &gain in gains {
+                    black_box(volt_to_db_f(black_box(gain)));
+                }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("log10", name), &gains, |b, gains| {
+            b.iter(|| {
+                for &gain in gains {
+                    black_box((20.0 * black_box(gain).log10()).round() as i32);
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_db_to_volt, bench_volt_to_db);
+criterion_main!(benches);
@@ -0,0 +1,116 @@
+//! Criterion benchmarks of the smoothers.
+//!
+//! Run with `cargo bench --bench smoothing`, add `--features simd` to include
+//! `TinySmoother4`. The smoothers are fed with a constant target (the common case of a
+//! parameter at rest), a target stepping every 64 samples (block-wise automation) and a
+//! random target per sample (sample-accurate automation).
+
+use audio_utils::TinySmoother;
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use rand::{Rng, SeedableRng, rngs::SmallRng};
+use std::hint::black_box;
+
+/// Number of targets per iteration
+const TARGETS: usize = 1024;
+
+fn target_inputs() -> Vec<(&'static str, Vec<f32>)> {
+    let mut rng = SmallRng::seed_from_u64(0x5300_7BED);
+    let mut blockwise = Vec::with_capacity(TARGETS);
+    while blockwise.len() < TARGETS {
+        let target = rng.random_range(0.0..1.0);
+        blockwise.extend(std::iter::repeat_n(target, 64));
+    }
+    vec![
+        ("constant", vec![0.5; TARGETS]),
+        ("blockwise", blockwise),
+        ("per_sample", (0..TARGETS).map(|_| rng.random_range(0.0..1.0)).collect()),
+    ]
+}
+
+fn bench_tiny_smoother(c: &mut Criterion) {
+    let mut group = c.benchmark_group("tiny_smoother");
+    group.throughput(Throughput::Elements(TARGETS as u64));
+    for (name, targets) in target_inputs() {
+        group.bench_with_input(BenchmarkId::new("next", name), &targets, |b, targets| {
+            let mut smoother = TinySmoother::default();
+            b.iter(|| {
+                for &target in targets {
+                    black_box(smoother.next(black_box(target)));
+                }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("next_f64", name), &targets, |b, targets| {
+            let mut smoother = TinySmoother::default();
+            b.iter(|| {
+                for &target in targets {
+                    black_box(smoother.next_f64(black_box(target as f64)));
+                }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("passthrough", name), &targets, |b, targets| {
+            // beta == 0.0 takes the fast path in next()
+            let mut smoother = TinySmoother::new(0.0, 0.0);
+            b.iter(|| {
+                for &target in targets {
+                    black_box(smoother.next(black_box(target)));
+                }
+            })
+        });
+        #[cfg(feature = "simd")]
+        group.bench_with_input(BenchmarkId::new("four_lanes", name), &targets, |b, targets| {
+            // four voices per call, so a quarter of the calls for the same number of values
+            let mut smoother = audio_utils::TinySmoother4::default();
+            b.iter(|| {
+                for chunk in targets.chunks_exact(4) {
+                    let lanes = [chunk[0], chunk[1], chunk[2], chunk[3]];
+                    black_box(smoother.next(black_box(lanes)));
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+/// A smoothed gain applied to a buffer, the typical caller loop. Targets contain a few
+/// non-finite values to cover the guard in `next()`, which is compared with the branchless
+/// select of `next_branchless()`.
+fn bench_gain_loop(c: &mut Criterion) {
+    let mut group = c.benchmark_group("gain_loop");
+    group.throughput(Throughput::Elements(TARGETS as u64));
+    let mut rng = SmallRng::seed_from_u64(0x6A1B_100B);
+    let input: Vec<f32> = (0..TARGETS).map(|_| rng.random_range(-1.0..1.0)).collect();
+    let mut inputs = target_inputs();
+    inputs.push((
+        "sparse_nan",
+        (0..TARGETS)
+            .map(|n| if n % 100 == 0 { f32::NAN } else { 0.5 })
+            .collect(),
+    ));
+    for (name, targets) in inputs {
+        group.bench_with_input(BenchmarkId::new("next", name), &targets, |b, targets| {
+            let mut smoother = TinySmoother::default();
+            let mut output = vec![0.0f32; TARGETS];
+            b.iter(|| {
+                for ((out, &sample), &target) in output.iter_mut().zip(&input).zip(targets) {
+                    *out = sample * smoother.next(black_box(target));
+                }
+                black_box(&mut output);
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("next_branchless", name), &targets, |b, targets| {
+            // the same loop with a branchless select instead of the finite check
+            let mut smoother = TinySmoother::default();
+            let mut output = vec![0.0f32; TARGETS];
+            b.iter(|| {
+                for ((out, &sample), &target) in output.iter_mut().zip(&input).zip(targets) {
+                    *out = sample * smoother.next_branchless(black_box(target));
+                }
+                black_box(&mut output);
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_tiny_smoother, bench_gain_loop);
+criterion_main!(benches);
@@ -0,0 +1,81 @@
+use audio_utils::crossfade_gains;
+use rand::{Rng, SeedableRng, rngs::SmallRng};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Number of crossfade positions per sweep.
+const POSITIONS: usize = 4096;
+
+/// Audio "realtime" budget in nanoseconds per sample.
+const NS_PER_SAMPLE_48K: f64 = 20_833.333_333_333_332;
+
+/// Random positions in [0.0, 1.0], with a fixed seed.
+/// Intent: avoid monotonic access patterns in the table.
+fn mixed_positions() -> Vec<f32> {
+    let mut rng = SmallRng::seed_from_u64(0xC055_FADE_u64);
+    (0..POSITIONS).map(|_| rng.random_range(0.0..=1.0)).collect()
+}
+
+/// Reference implementation with `cos()`/`sin()`.
+#[inline(always)]
+fn cos_sin_ref(position: f32) -> (f32, f32) {
+    let angle = position * std::f32::consts::FRAC_PI_2;
+    (angle.cos(), angle.sin())
+}
+
+/// Measure the average time per sample (ns/op) of a crossfade between two buffers with a
+/// new position on every sample, the way a dry/wet mix uses the gains. The whole block is
+/// processed once per sweep, repeated `sweeps` times; the best of a few runs is taken to
+/// reduce incidental noise.
+fn measure<F: Fn(f32) -> (f32, f32)>(name: &str, xs: &[f32], sweeps: usize, f: F) -> f64 {
+    let dry = vec![0.5f32; xs.len()];
+    let wet = vec![-0.25f32; xs.len()];
+    let mut out = vec![0.0f32; xs.len()];
+
+    let runs = 5;
+    let mut best = Duration::MAX;
+    for _ in 0..runs {
+        let start = Instant::now();
+        for _ in 0..sweeps {
+            let xs = black_box(xs);
+            for (((out, &dry), &wet), &x) in out.iter_mut().zip(&dry).zip(&wet).zip(xs) {
+                let (a, b) = f(x);
+                *out = dry * a + wet * b;
+            }
+            black_box(&mut out);
+        }
+        best = best.min(start.elapsed());
+    }
+
+    let ns_per_op = best.as_secs_f64() * 1e9 / (xs.len() * sweeps) as f64;
+    println!("{name}: best-of-{runs}  sweeps={sweeps}  ⇒  {ns_per_op:.3} ns/op");
+    ns_per_op
+}
+
+fn main() {
+    let xs = mixed_positions();
+    let sweeps = 5_000;
+
+    println!("crossfade_gains vs. cos/sin using Instant timing");
+    println!("Positions: {}", xs.len());
+    println!();
+
+    let lut = measure("LUT", &xs, sweeps, crossfade_gains);
+    let trig = measure("cos/sin", &xs, sweeps, cos_sin_ref);
+
+    let max_error = xs
+        .iter()
+        .map(|&x| {
+            let (a, b) = crossfade_gains(x);
+            let (ref_a, ref_b) = cos_sin_ref(x);
+            (a - ref_a).abs().max((b - ref_b).abs())
+        })
+        .fold(0.0f32, f32::max);
+
+    println!();
+    println!("=== Summary ===");
+    println!("LUT:     {:.3} ns/op   @48k {:.0}×", lut, NS_PER_SAMPLE_48K / lut);
+    println!("cos/sin: {:.3} ns/op   @48k {:.0}×", trig, NS_PER_SAMPLE_48K / trig);
+    println!("Speedup (LUT / cos/sin): {:.2}×", trig / lut);
+    println!("Max. deviation: {:e}", max_error);
+}
@@ -0,0 +1,88 @@
+use audio_utils::decibels::{db_to_volt, db_to_volt_slice};
+use rand::{Rng, SeedableRng, rngs::SmallRng};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Benchmark domain: align with the LUT's coverage.
+const MIN_DB: i32 = -100;
+const MAX_DB: i32 = 27;
+
+/// Length of one automation buffer.
+const BUFFER_LEN: usize = 4096;
+
+/// Deterministically randomised automation buffer.
+/// Intent: avoid monotonic access patterns and branch prediction artifacts.
+fn random_db_values() -> Vec<i32> {
+    let mut rng = SmallRng::seed_from_u64(0xDEC1_BA5E_u64); // fixed seed
+    (0..BUFFER_LEN)
+        .map(|_| rng.random_range(MIN_DB..=MAX_DB))
+        .collect()
+}
+
+/// Scalar reference: one `db_to_volt()` call per element.
+fn scalar_loop(dbs: &[i32], out: &mut [f32]) {
+    for (volt, &db) in out.iter_mut().zip(dbs) {
+        *volt = db_to_volt(db);
+    }
+}
+
+/// Measure average time per converted value (ns/op) over `sweeps` conversions of the whole
+/// buffer. We perform a short warmup and take the best of a few runs to reduce incidental
+/// noise (scheduler, turbo, cache state).
+fn measure<F: Fn(&[i32], &mut [f32])>(name: &str, dbs: &[i32], sweeps: usize, f: F) -> f64 {
+    let mut out = vec![0.0f32; dbs.len()];
+
+    // Warmup
+    f(black_box(dbs), &mut out);
+    black_box(&out);
+
+    let runs = 5;
+    let mut best: Duration = Duration::from_secs(u64::MAX);
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        for _ in 0..sweeps {
+            f(black_box(dbs), &mut out);
+            black_box(&out);
+        }
+        let dt = start.elapsed();
+        if dt < best {
+            best = dt;
+        }
+    }
+
+    let total_calls = (dbs.len() * sweeps) as f64;
+    let ns_per_op = best.as_secs_f64() * 1e9 / total_calls;
+    println!(
+        "{name}: best-of-{runs}  sweeps={sweeps}  total_calls={total_calls:.0}  ⇒  {ns_per_op:.3} ns/op"
+    );
+    ns_per_op
+}
+
+fn main() {
+    // Prepare inputs once.
+    let dbs = random_db_values();
+
+    // Verify that both paths agree before timing them.
+    let mut expected = vec![0.0f32; dbs.len()];
+    let mut actual = vec![0.0f32; dbs.len()];
+    scalar_loop(&dbs, &mut expected);
+    db_to_volt_slice(&dbs, &mut actual);
+    assert_eq!(expected, actual);
+
+    // Adjust if your machine is very fast/slow to keep runs under ~1–2 seconds.
+    let sweeps = 20_000usize;
+
+    println!("db_to_volt_slice summary using Instant timing");
+    println!("Range: [{}, {}] dB, buffer length = {}", MIN_DB, MAX_DB, dbs.len());
+    println!();
+
+    let scalar = measure("scalar loop", &dbs, sweeps, scalar_loop);
+    let slice = measure("db_to_volt_slice", &dbs, sweeps, db_to_volt_slice);
+
+    println!();
+    println!("=== Summary ===");
+    println!("scalar: {:.3} ns/op", scalar);
+    println!("slice:  {:.3} ns/op", slice);
+    println!("Speedup (scalar / slice): {:.2}×", scalar / slice);
+}
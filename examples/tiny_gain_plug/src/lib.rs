@@ -1,15 +1,15 @@
 //! This is a simplified version of the gain plugin example from
 //! the [NIH-plug](https://github.com/robbert-vdh/nih-plug) documentation.
-//! It demonstrates the use of `audio_utils::{DbToGain, TinySmoother}`.
+//! It demonstrates the use of `audio_utils::{scale_sample, SmoothedGain}`.
 //! 
 
-use audio_utils::{DbToVolt, TinySmoother};
+use audio_utils::{scale_sample, SmoothedGain};
 use nih_plug::prelude::*;
 use std::sync::Arc;
 
 struct TinyGainPlug {
     params: Arc<TinyGainParams>,
-    smoother: TinySmoother,
+    gain: SmoothedGain,
 }
 #[derive(Params)]
 struct TinyGainParams {
@@ -22,7 +22,7 @@ impl Default for TinyGainPlug {
     fn default() -> Self {
         Self {
             params: Arc::new(TinyGainParams::default()),
-            smoother: TinySmoother::default(),
+            gain: SmoothedGain::default(),
         }
     }
 }
@@ -67,7 +67,7 @@ impl Plugin for TinyGainPlug {
         self.params.clone()
     }
     fn reset(&mut self) {
-        self.smoother.reset();
+        self.gain.reset();
     }
 
     fn process(
@@ -77,14 +77,15 @@ impl Plugin for TinyGainPlug {
         _context: &mut impl ProcessContext<Self>,
     ) -> ProcessStatus {
        
-        let voltage_ratio = self.params.gain_db.value().to_volt();
+        self.gain.set_db(self.params.gain_db.value() as f32);
 
         for channel_samples in buffer.iter_samples() {
-            // Use TinySmoother to smooth the gain value across all samples.
-            let smoothed_voltage = self.smoother.next(voltage_ratio);
+            // The same smoothed gain for all channels of a sample.
+            let gain = self.gain.next_gain();
 
             for sample in channel_samples {
-                *sample *= smoothed_voltage;
+                // a non-finite input sample is muted instead of passed on
+                *sample = scale_sample(*sample, gain);
             }
         }
 
@@ -0,0 +1,126 @@
+//! `N` `TinySmoother`s in one, e.g. for the gains of the bands of a multiband processor.
+//!
+//! All elements share one smoothing coefficient and keep their own f64 state. Each element
+//! produces bit-identical output to a scalar `TinySmoother` with the same `beta`. For four
+//! elements with vectorised processing, see `TinySmoother4` (`simd` feature).
+//!
+//! # Examples
+//!
+//! ```
+//! use audio_utils::ArraySmoother;
+//!
+//! // one gain per band of a 3-band compressor
+//! let mut band_gains = ArraySmoother::<3>::new(0.999, 1.0);
+//! let gains = band_gains.next([0.5, 1.0, 0.8]);
+//! ```
+
+use crate::tiny_smoother::beta_from_half_life;
+
+/// `N` exponential smoothers with a shared `beta`, see `TinySmoother`.
+#[derive(Debug, Clone)]
+pub struct ArraySmoother<const N: usize> {
+    last_values: [f64; N],
+    beta: f64,
+    start_value: f32,
+}
+
+impl<const N: usize> Default for ArraySmoother<N> {
+    /// Creates `N` smoothers like `TinySmoother::default()`: a 500-sample half-life (~10ms at
+    /// 48 kHz), starting at 0.0.
+    fn default() -> ArraySmoother<N> {
+        ArraySmoother::new(beta_from_half_life(500.0), 0.0)
+    }
+}
+
+impl<const N: usize> ArraySmoother<N> {
+    /// Creates `N` smoothers with a custom smoothing coefficient, see `TinySmoother::new()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `beta` is not in range [0.0, 1.0) or if `start_value` is not finite.
+    pub fn new(beta: f64, start_value: f32) -> ArraySmoother<N> {
+        assert!(
+            (0.0..1.0).contains(&beta),
+            "Beta must be in range [0.0, 1.0), got {}",
+            beta
+        );
+        assert!(
+            start_value.is_finite(),
+            "Start value must be finite, got {}",
+            start_value
+        );
+        ArraySmoother {
+            last_values: [start_value as f64; N],
+            beta,
+            start_value,
+        }
+    }
+
+    /// Processes the next target of each element, like `TinySmoother::next()`.
+    ///
+    /// A non-finite target leaves its element unchanged for this step.
+    ///
+    /// # Real-time safety
+    ///
+    /// Never panics and never allocates, for any input including `NaN` and infinities.
+    #[inline]
+    pub fn next(&mut self, targets: [f32; N]) -> [f32; N] {
+        for (last_value, target) in self.last_values.iter_mut().zip(targets) {
+            if target.is_finite() {
+                let target = target as f64;
+                *last_value = target - self.beta * (target - *last_value);
+            }
+        }
+        self.last_values.map(|value| value as f32)
+    }
+
+    /// Resets all elements to the starting value.
+    pub fn reset(&mut self) {
+        self.last_values = [self.start_value as f64; N];
+    }
+}
+
+//--- Tests ---------------------------------------------------------------------------------------
+//
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TinySmoother;
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn each_element_matches_a_scalar_smoother() {
+        let beta = beta_from_half_life(100.0);
+        let mut bands = ArraySmoother::<4>::new(beta, 0.5);
+        let mut scalars: [TinySmoother; 4] = std::array::from_fn(|_| TinySmoother::new(beta, 0.5));
+
+        let mut rng = SmallRng::seed_from_u64(60);
+        for _ in 0..10_000 {
+            let targets: [f32; 4] = std::array::from_fn(|_| match rng.random_range(0..100) {
+                0 => f32::NAN,
+                _ => rng.random_range(-2.0..2.0),
+            });
+            let values = bands.next(targets);
+            for band in 0..4 {
+                assert_eq!(values[band], scalars[band].next(targets[band]));
+            }
+        }
+    }
+
+    #[test]
+    fn elements_are_independent() {
+        let mut bands = ArraySmoother::<4>::new(0.5, 0.0);
+        bands.next([1.0, 0.0, 0.0, 0.0]);
+        let values = bands.next([1.0, 0.0, -1.0, f32::INFINITY]);
+        assert_eq!(values, [0.75, 0.0, -0.5, 0.0]);
+    }
+
+    #[test]
+    fn reset_returns_all_elements_to_start() {
+        let mut bands = ArraySmoother::<8>::new(0.5, 0.25);
+        bands.next([1.0; 8]);
+        bands.reset();
+        assert_eq!(bands.next([0.25; 8]), [0.25; 8]);
+    }
+}
@@ -0,0 +1,196 @@
+//! A one-pole smoother with separate coefficients for rising and falling values.
+//!
+//! Envelope followers and compressors need different time constants for rising (attack)
+//! and falling (release) signals, which a single `beta` can't express.
+//! `AttackReleaseSmoother` uses the same drift-free recurrence as `TinySmoother`, but picks
+//! the attack coefficient while the target is above the current value and the release
+//! coefficient while it is below.
+
+use crate::tiny_smoother::{beta_from_half_life, ms_to_samples};
+use crate::smoother::Smoother;
+
+/// One-pole smoother with asymmetric attack/release coefficients.
+///
+/// # Example
+/// ```
+/// use audio_utils::AttackReleaseSmoother;
+///
+/// // fast attack, slow release
+/// let mut envelope = AttackReleaseSmoother::from_times(48_000.0, 1.0, 100.0, 0.0);
+///
+/// let level = envelope.next(0.8f32.abs());
+/// ```
+pub struct AttackReleaseSmoother {
+    /// Current filtered value (f64 for numerical stability)
+    last_value: f64,
+    /// Value to reset to
+    start_value: f32,
+    /// Smoothing coefficient for rising values, in range [0.0, 1.0)
+    attack_beta: f64,
+    /// Smoothing coefficient for falling values, in range [0.0, 1.0)
+    release_beta: f64,
+}
+
+impl AttackReleaseSmoother {
+    /// Creates a smoother with custom smoothing coefficients, see `TinySmoother::new()`.
+    ///
+    /// # Parameters
+    /// * `attack_beta` - Smoothing coefficient in range [0.0, 1.0) while the target is above
+    ///   the current value
+    /// * `release_beta` - Smoothing coefficient in range [0.0, 1.0) while the target is below
+    ///   the current value
+    /// * `start_value` - the value the smoother should start from when reset
+    ///
+    /// # Panics
+    ///
+    /// Panics if a coefficient is not in range [0.0, 1.0) or if `start_value` is not finite.
+    pub fn new(attack_beta: f64, release_beta: f64, start_value: f32) -> AttackReleaseSmoother {
+        assert!(
+            (0.0..1.0).contains(&attack_beta),
+            "Attack beta must be in range [0.0, 1.0), got {}",
+            attack_beta
+        );
+        assert!(
+            (0.0..1.0).contains(&release_beta),
+            "Release beta must be in range [0.0, 1.0), got {}",
+            release_beta
+        );
+        assert!(
+            start_value.is_finite(),
+            "Start value must be finite, got {}",
+            start_value
+        );
+        AttackReleaseSmoother {
+            last_value: start_value as f64,
+            start_value,
+            attack_beta,
+            release_beta,
+        }
+    }
+
+    /// Creates a smoother from attack and release times.
+    ///
+    /// # Parameters
+    /// * `sample_rate` - Sample rate in Hz
+    /// * `attack_ms` - Time in milliseconds after which a rising step is halfway reached
+    /// * `release_ms` - Time in milliseconds after which a falling step is halfway reached
+    /// * `start_value` - the value the smoother should start from when reset
+    ///
+    /// # Panics
+    ///
+    /// Panics if a time is negative or if `start_value` is not finite.
+    pub fn from_times(
+        sample_rate: f32,
+        attack_ms: f32,
+        release_ms: f32,
+        start_value: f32,
+    ) -> AttackReleaseSmoother {
+        AttackReleaseSmoother::new(
+            beta_from_half_life(ms_to_samples(sample_rate, attack_ms)),
+            beta_from_half_life(ms_to_samples(sample_rate, release_ms)),
+            start_value,
+        )
+    }
+
+    /// Processes the next target value, using the attack coefficient if the target is above
+    /// the current value and the release coefficient if it is below.
+    /// Non-finite targets are ignored and return the current value.
+    pub fn next(&mut self, target: f32) -> f32 {
+        if !target.is_finite() {
+            return self.last_value as f32;
+        }
+        let target = target as f64;
+        let beta = if target > self.last_value {
+            self.attack_beta
+        } else {
+            self.release_beta
+        };
+        let new_value = target - beta * (target - self.last_value);
+        self.last_value = new_value;
+        new_value as f32
+    }
+
+    /// Resets the smoother to its starting value.
+    pub fn reset(&mut self) {
+        self.last_value = self.start_value as f64;
+    }
+}
+
+impl Smoother for AttackReleaseSmoother {
+    #[inline]
+    fn next(&mut self, target: f32) -> f32 {
+        AttackReleaseSmoother::next(self, target)
+    }
+
+    fn reset(&mut self) {
+        AttackReleaseSmoother::reset(self);
+    }
+
+    fn current_value(&self) -> f32 {
+        self.last_value as f32
+    }
+}
+
+//--- Tests ---------------------------------------------------------------------------------------
+//
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Number of samples until the output crosses half of the step from `from` to `to`.
+    fn samples_to_half(smoother: &mut AttackReleaseSmoother, from: f32, to: f32) -> usize {
+        let half = (from + to) / 2.0;
+        (1..)
+            .find(|_| {
+                let value = smoother.next(to);
+                if to > from { value >= half } else { value <= half }
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn rising_step_follows_attack_time() {
+        // 1 ms attack = 48 samples half-life
+        let mut smoother = AttackReleaseSmoother::from_times(48_000.0, 1.0, 100.0, 0.0);
+        let samples = samples_to_half(&mut smoother, 0.0, 1.0);
+        assert!((47..=49).contains(&samples), "samples = {samples}");
+    }
+
+    #[test]
+    fn falling_step_follows_release_time() {
+        // 100 ms release = 4800 samples half-life
+        let mut smoother = AttackReleaseSmoother::from_times(48_000.0, 1.0, 100.0, 1.0);
+        let samples = samples_to_half(&mut smoother, 1.0, 0.0);
+        assert!((4799..=4801).contains(&samples), "samples = {samples}");
+    }
+
+    #[test]
+    fn smoother_ignores_non_finite_targets() {
+        let mut smoother = AttackReleaseSmoother::new(0.5, 0.5, 0.0);
+        let last_valid = smoother.next(1.0);
+        assert_eq!(smoother.next(f32::NAN), last_valid);
+        assert_eq!(smoother.next(f32::NEG_INFINITY), last_valid);
+    }
+
+    #[test]
+    fn smoother_can_be_reset() {
+        let mut smoother = AttackReleaseSmoother::new(0.9, 0.99, 0.25);
+        for _ in 0..100 {
+            smoother.next(1.0);
+        }
+        smoother.reset();
+        assert_eq!(smoother.next(0.25), 0.25);
+    }
+
+    #[test]
+    #[should_panic(expected = "Attack beta must be in range [0.0, 1.0)")]
+    fn smoother_panics_on_invalid_attack_beta() {
+        let _smoother = AttackReleaseSmoother::new(1.0, 0.5, 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Release beta must be in range [0.0, 1.0)")]
+    fn smoother_panics_on_invalid_release_beta() {
+        let _smoother = AttackReleaseSmoother::new(0.5, -0.1, 0.0);
+    }
+}
@@ -0,0 +1,212 @@
+//! Helpers that apply gains to whole sample buffers.
+//!
+//! A gain stage must keep all channels of a frame phase-aligned, so a smoothed gain
+//! advances once per frame and the same value is applied to every channel of that frame.
+
+use crate::tiny_smoother::TinySmoother;
+
+/// Applies a smoothed gain to an interleaved multi-channel buffer.
+///
+/// The smoother advances once per frame toward `target`, and all `channels` samples of a
+/// frame are multiplied by the same smoothed gain.
+///
+/// # Panics
+///
+/// Panics if `channels` is 0 or `buffer.len()` is not a multiple of `channels`.
+///
+/// # Example
+/// ```
+/// use audio_utils::{apply_smoothed_gain, DbToVolt, TinySmoother};
+///
+/// let mut smoother = TinySmoother::default();
+/// // interleaved stereo: L R L R ...
+/// let mut buffer = [0.5f32; 512];
+///
+/// apply_smoothed_gain(&mut smoother, (-6).to_volt(), &mut buffer, 2);
+/// ```
+pub fn apply_smoothed_gain(
+    smoother: &mut TinySmoother,
+    target: f32,
+    buffer: &mut [f32],
+    channels: usize,
+) {
+    assert!(channels > 0, "Channel count must be greater than 0");
+    assert!(
+        buffer.len().is_multiple_of(channels),
+        "Buffer length {} is not a multiple of the channel count {}",
+        buffer.len(),
+        channels
+    );
+    for frame in buffer.chunks_exact_mut(channels) {
+        let gain = smoother.next(target);
+        for sample in frame {
+            *sample *= gain;
+        }
+    }
+}
+
+/// Multiplies `sample` by `gain`, returning 0.0 instead of a non-finite result.
+///
+/// A single `NaN` or infinite sample (from an upstream bug or a blown-up filter) would
+/// otherwise propagate through every following gain, filter and mix stage. Muting it
+/// contains the damage to that one sample.
+///
+/// # Example
+/// ```
+/// use audio_utils::scale_sample;
+///
+/// assert_eq!(scale_sample(0.5, 0.5), 0.25);
+/// assert_eq!(scale_sample(f32::NAN, 0.5), 0.0);
+/// assert_eq!(scale_sample(f32::MAX, 2.0), 0.0);
+/// ```
+///
+/// # Real-time safety
+///
+/// Never panics and never allocates.
+#[inline]
+pub fn scale_sample(sample: f32, gain: f32) -> f32 {
+    let scaled = sample * gain;
+    if scaled.is_finite() { scaled } else { 0.0 }
+}
+
+/// Multiplies every sample of `buffer` by `gain` via `scale_sample()`, so non-finite
+/// results are replaced by 0.0.
+///
+/// # Example
+/// ```
+/// use audio_utils::scale_block;
+///
+/// let mut buffer = [0.5, f32::NAN, -0.5, f32::INFINITY];
+/// scale_block(&mut buffer, 0.5);
+///
+/// assert_eq!(buffer, [0.25, 0.0, -0.25, 0.0]);
+/// ```
+///
+/// # Real-time safety
+///
+/// Never panics and never allocates.
+pub fn scale_block(buffer: &mut [f32], gain: f32) {
+    for sample in buffer {
+        *sample = scale_sample(*sample, gain);
+    }
+}
+
+/// Multiplies every sample of `buffer` by `gain` with explicit SIMD, 8 samples at a time.
+///
+/// The output is identical to the scalar loop `*sample *= gain`: the multiplication is
+/// elementwise, so there is no reordering of floating point operations. Unlike
+/// `scale_block()`, non-finite results are passed on. Uses AVX when the CPU supports it and
+/// finishes the tail (and all other CPUs) with the scalar loop.
+///
+/// # Example
+/// ```
+/// use audio_utils::apply_gain_simd;
+///
+/// let mut buffer = [0.5f32; 100];
+/// apply_gain_simd(&mut buffer, 0.5);
+///
+/// assert!(buffer.iter().all(|&sample| sample == 0.25));
+/// ```
+///
+/// # Real-time safety
+///
+/// Never panics and never allocates.
+#[cfg(feature = "simd")]
+pub fn apply_gain_simd(buffer: &mut [f32], gain: f32) {
+    let done = crate::simd::scale(buffer, gain);
+    for sample in &mut buffer[done..] {
+        *sample *= gain;
+    }
+}
+
+//--- Tests ---------------------------------------------------------------------------------------
+//
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_smoothed_gain_uses_one_gain_per_frame() {
+        let mut smoother = TinySmoother::default();
+        let mut buffer = [1.0f32; 2 * 64];
+
+        apply_smoothed_gain(&mut smoother, 1.0, &mut buffer, 2);
+
+        let mut reference = TinySmoother::default();
+        for frame in buffer.chunks_exact(2) {
+            // both channels of a frame get the identical gain ...
+            assert_eq!(frame[0], frame[1]);
+            // ... and the gain evolves per frame, not per sample
+            assert_eq!(frame[0], reference.next(1.0));
+        }
+        assert!(buffer[0] < buffer[126]);
+    }
+
+    #[test]
+    fn apply_smoothed_gain_continues_across_buffers() {
+        let mut smoother = TinySmoother::default();
+        let mut first = [1.0f32; 2 * 32];
+        let mut second = [1.0f32; 2 * 32];
+        apply_smoothed_gain(&mut smoother, 1.0, &mut first, 2);
+        apply_smoothed_gain(&mut smoother, 1.0, &mut second, 2);
+
+        let mut reference = TinySmoother::default();
+        for frame in first.chunks_exact(2).chain(second.chunks_exact(2)) {
+            assert_eq!(frame[0], reference.next(1.0));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "is not a multiple of the channel count")]
+    fn apply_smoothed_gain_panics_on_partial_frame() {
+        let mut smoother = TinySmoother::default();
+        apply_smoothed_gain(&mut smoother, 1.0, &mut [0.0; 5], 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Channel count must be greater than 0")]
+    fn apply_smoothed_gain_panics_on_zero_channels() {
+        let mut smoother = TinySmoother::default();
+        apply_smoothed_gain(&mut smoother, 1.0, &mut [0.0; 4], 0);
+    }
+
+    #[test]
+    fn scale_sample_mutes_non_finite_results() {
+        for sample in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+            assert_eq!(scale_sample(sample, 0.5), 0.0);
+        }
+        assert_eq!(scale_sample(1.0, f32::NAN), 0.0);
+        assert_eq!(scale_sample(1.0, f32::INFINITY), 0.0);
+        // overflow of two finite values
+        assert_eq!(scale_sample(f32::MAX, -2.0), 0.0);
+        // finite results pass unchanged
+        assert_eq!(scale_sample(-0.75, 2.0), -1.5);
+    }
+
+    #[test]
+    fn scale_block_keeps_buffer_finite() {
+        let mut buffer = [0.1, f32::NAN, 0.2, f32::INFINITY, f32::NEG_INFINITY, 0.3];
+        scale_block(&mut buffer, 2.0);
+
+        assert!(buffer.iter().all(|sample| sample.is_finite()));
+        assert_eq!(buffer, [0.2, 0.0, 0.4, 0.0, 0.0, 0.6]);
+    }
+
+    #[test]
+    #[cfg(feature = "simd")]
+    fn apply_gain_simd_matches_scalar_loop() {
+        use rand::{Rng, SeedableRng, rngs::SmallRng};
+
+        let mut rng = SmallRng::seed_from_u64(0x5133_D6A1);
+        // odd length to cover the scalar tail
+        let input: Vec<f32> = (0..1027).map(|_| rng.random_range(-2.0..2.0)).collect();
+        for gain in [0.0, 0.5, -1.25, rng.random_range(0.0..20.0), f32::MIN_POSITIVE] {
+            let mut simd = input.clone();
+            apply_gain_simd(&mut simd, gain);
+            let scalar: Vec<f32> = input.iter().map(|&sample| sample * gain).collect();
+            let simd_bits: Vec<u32> = simd.iter().map(|sample| sample.to_bits()).collect();
+            let scalar_bits: Vec<u32> = scalar.iter().map(|sample| sample.to_bits()).collect();
+            assert_eq!(simd_bits, scalar_bits, "gain = {gain}");
+        }
+    }
+}
@@ -0,0 +1,137 @@
+//! Declicked switching between the processed and the dry signal when a plugin is bypassed.
+//!
+//! Hard-switching between two signals that differ clicks. `BypassFade` smooths the bypass
+//! amount with a `TinySmoother` and crossfades linearly: the dry and the processed signal
+//! are usually strongly correlated, so the linear law keeps the level constant where an
+//! equal-power law would bump it by up to 3 dB.
+//!
+//! # Examples
+//!
+//! ```
+//! use audio_utils::BypassFade;
+//!
+//! let mut bypass = BypassFade::new(48_000.0, 5.0);
+//! let (dry, wet) = (0.5f32, 0.8f32);
+//!
+//! let out = bypass.mix(dry, wet, false);
+//! assert_eq!(out, wet);
+//! ```
+
+use crate::decibels::MIN_GAIN;
+use crate::tiny_smoother::{beta_from_half_life, ms_to_samples, TinySmoother};
+
+/// Crossfades between the processed (wet) and the dry signal on bypass changes.
+pub struct BypassFade {
+    /// Bypass amount: 0.0 = processed, 1.0 = bypassed (dry)
+    amount: TinySmoother,
+}
+
+impl Default for BypassFade {
+    /// Creates an active (not bypassed) fade with the smoothing time of
+    /// `TinySmoother::default()`.
+    fn default() -> BypassFade {
+        BypassFade::with_smoother(TinySmoother::default())
+    }
+}
+
+impl BypassFade {
+    /// Creates an active (not bypassed) fade.
+    ///
+    /// # Parameters
+    /// * `sample_rate` - Sample rate in Hz
+    /// * `half_life_ms` - Time in milliseconds after which a fade is halfway done
+    ///
+    /// # Panics
+    ///
+    /// Panics if `half_life_ms` is negative.
+    pub fn new(sample_rate: f32, half_life_ms: f32) -> BypassFade {
+        let beta = beta_from_half_life(ms_to_samples(sample_rate, half_life_ms));
+        BypassFade::with_smoother(TinySmoother::new(beta, 0.0))
+    }
+
+    fn with_smoother(mut amount: TinySmoother) -> BypassFade {
+        // settle exactly on 0.0 or 1.0, so `is_settled()` becomes true
+        amount.set_snap_threshold(MIN_GAIN);
+        BypassFade { amount }
+    }
+
+    /// Returns the output sample for the current bypass state, fading toward `dry` while
+    /// `bypassed` is `true` and toward `wet` (the processed signal) while it is `false`.
+    #[inline]
+    pub fn mix(&mut self, dry: f32, wet: f32, bypassed: bool) -> f32 {
+        let amount = self.amount.next(if bypassed { 1.0 } else { 0.0 });
+        wet + amount * (dry - wet)
+    }
+
+    /// Returns `true` once a fade has finished. If the plugin is bypassed then, the host can
+    /// skip processing and pass the dry signal through.
+    pub fn is_settled(&self) -> bool {
+        !self.amount.is_smoothing()
+    }
+
+    /// Returns `true` if the fade has settled on the dry signal.
+    pub fn is_bypassed(&self) -> bool {
+        self.is_settled() && self.amount.current_value_f64() == 1.0
+    }
+
+    /// Jumps to the active (not bypassed) state without fading.
+    pub fn reset(&mut self) {
+        self.amount.reset();
+    }
+}
+
+//--- Tests ---------------------------------------------------------------------------------------
+//
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toggling_bypass_ramps_without_clicks() {
+        let mut bypass = BypassFade::new(48_000.0, 1.0);
+        let (dry, wet) = (1.0, -1.0);
+        assert_eq!(bypass.mix(dry, wet, false), wet);
+
+        let mut last = wet;
+        for _ in 0..2000 {
+            let out = bypass.mix(dry, wet, true);
+            // monotonic, at most ~3% of the distance per sample at 48 samples half-life
+            assert!(out >= last && out - last < 0.03);
+            last = out;
+        }
+        assert_eq!(last, dry);
+
+        for _ in 0..2000 {
+            let out = bypass.mix(dry, wet, false);
+            assert!(out <= last && last - out < 0.03);
+            last = out;
+        }
+        assert_eq!(last, wet);
+    }
+
+    #[test]
+    fn bypass_eventually_settles() {
+        let mut bypass = BypassFade::new(48_000.0, 1.0);
+        assert!(bypass.is_settled());
+        assert!(!bypass.is_bypassed());
+
+        bypass.mix(0.0, 0.0, true);
+        assert!(!bypass.is_settled());
+
+        let samples = (1..).find(|_| {
+            bypass.mix(0.0, 0.0, true);
+            bypass.is_settled()
+        });
+        assert!(samples.unwrap() < 48 * 20);
+        assert!(bypass.is_bypassed());
+    }
+
+    #[test]
+    fn bypass_can_be_reset() {
+        let mut bypass = BypassFade::default();
+        bypass.mix(0.0, 0.0, true);
+        bypass.reset();
+        assert!(bypass.is_settled());
+        assert_eq!(bypass.mix(1.0, 0.5, false), 0.5);
+    }
+}
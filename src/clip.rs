@@ -0,0 +1,178 @@
+//! Clipping for the gain stage: keeps boosted signals within a ceiling.
+//!
+//! `hard_clip()` cuts everything above the ceiling, `soft_clip()` bends the signal smoothly
+//! toward it with a `tanh` curve, trading a little saturation for the absence of the harsh
+//! harmonics of hard clipping. The `_db` variants take the ceiling in dB, converted via
+//! `db_to_volt_f()`.
+//!
+//! # Examples
+//!
+//! ```
+//! use audio_utils::{hard_clip, soft_clip};
+//!
+//! assert_eq!(hard_clip(1.5, 1.0), 1.0);
+//! assert!(soft_clip(1.5) < 1.0);
+//! ```
+
+use crate::decibels::db_to_volt_f;
+
+/// Largest `f32` below 1.0: `tanh()` rounds to 1.0 for inputs above ~9 in `f32`.
+const SOFT_CLIP_LIMIT: f32 = 1.0 - f32::EPSILON / 2.0;
+
+/// Soft-clips `sample` to the range (-1.0, 1.0) using `tanh`.
+///
+/// The curve is monotonic and odd-symmetric, nearly linear for small samples and approaches
+/// ±1.0 asymptotically. Where `tanh()` rounds to ±1.0 at `f32` precision, the output is held
+/// at the largest `f32` below 1.0, so the ceiling is never reached, not even for infinite
+/// input. `NaN` yields 0.0.
+///
+/// # Example
+/// ```
+/// use audio_utils::soft_clip;
+///
+/// assert!((soft_clip(0.01) - 0.01).abs() < 1e-6);
+/// assert!(soft_clip(10.0) < 1.0);
+/// ```
+#[inline]
+pub fn soft_clip(sample: f32) -> f32 {
+    if sample.is_nan() {
+        return 0.0;
+    }
+    sample.tanh().clamp(-SOFT_CLIP_LIMIT, SOFT_CLIP_LIMIT)
+}
+
+/// Soft-clips `sample` to the range (-ceiling, ceiling), see `soft_clip()`.
+///
+/// # Arguments
+///
+/// * `sample` - Input sample
+/// * `ceiling_db` - Ceiling in dB, e.g. -0.3 dBFS, clamped to [-100, 27] dB
+///
+/// # Example
+/// ```
+/// use audio_utils::soft_clip_db;
+///
+/// assert!(soft_clip_db(1.0, -6.0) < 0.5013);
+/// ```
+#[inline]
+pub fn soft_clip_db(sample: f32, ceiling_db: f32) -> f32 {
+    let ceiling = db_to_volt_f(ceiling_db);
+    ceiling * soft_clip(sample / ceiling)
+}
+
+/// Clamps `sample` to the range [-ceiling, ceiling]. `NaN` yields 0.0.
+///
+/// A negative or `NaN` ceiling is a caller bug: it fails a debug assertion, in release builds
+/// it is treated as 0.0 (silence).
+///
+/// # Example
+/// ```
+/// use audio_utils::hard_clip;
+///
+/// assert_eq!(hard_clip(-2.0, 0.5), -0.5);
+/// assert_eq!(hard_clip(0.25, 0.5), 0.25);
+/// ```
+///
+/// # Real-time safety
+///
+/// Never panics and never allocates in release builds, for any input including `NaN`.
+#[inline]
+pub fn hard_clip(sample: f32, ceiling: f32) -> f32 {
+    debug_assert!(ceiling >= 0.0, "Ceiling must be non-negative, got {}", ceiling);
+    let ceiling = if ceiling >= 0.0 { ceiling } else { 0.0 };
+    if sample.is_nan() {
+        return 0.0;
+    }
+    sample.clamp(-ceiling, ceiling)
+}
+
+/// Clamps `sample` to the range [-ceiling, ceiling], see `hard_clip()`.
+///
+/// # Arguments
+///
+/// * `sample` - Input sample
+/// * `ceiling_db` - Ceiling in dB, e.g. -0.3 dBFS, clamped to [-100, 27] dB
+///
+/// # Example
+/// ```
+/// use audio_utils::hard_clip_db;
+///
+/// assert_eq!(hard_clip_db(2.0, 0.0), 1.0);
+/// ```
+#[inline]
+pub fn hard_clip_db(sample: f32, ceiling_db: f32) -> f32 {
+    hard_clip(sample, db_to_volt_f(ceiling_db))
+}
+
+//--- Tests ---------------------------------------------------------------------------------------
+//
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inputs() -> impl Iterator<Item = f32> {
+        (-1000..=1000).map(|n| n as f32 / 100.0)
+    }
+
+    //--- soft_clip
+    #[test]
+    fn soft_clip_is_monotonic() {
+        let mut last = f32::NEG_INFINITY;
+        for x in inputs() {
+            let y = soft_clip(x);
+            assert!(y >= last, "soft_clip({}) = {} < {}", x, y, last);
+            last = y;
+        }
+    }
+
+    #[test]
+    fn soft_clip_is_odd_symmetric() {
+        for x in inputs() {
+            assert_eq!(soft_clip(-x), -soft_clip(x));
+        }
+    }
+
+    #[test]
+    fn soft_clip_stays_below_the_ceiling() {
+        for x in inputs() {
+            assert!(soft_clip(x).abs() < 1.0);
+            assert!(soft_clip_db(x, -6.0).abs() < db_to_volt_f(-6.0));
+        }
+        // the curve only approaches the ceiling
+        assert!(soft_clip(2.0) < 0.97);
+        assert!(soft_clip_db(1.0, 0.0) < 0.77);
+    }
+
+    #[test]
+    fn soft_clip_of_nan_is_silence() {
+        assert_eq!(soft_clip(f32::NAN), 0.0);
+        assert!(soft_clip(f32::INFINITY) < 1.0);
+        assert_eq!(soft_clip(f32::INFINITY), -soft_clip(f32::NEG_INFINITY));
+    }
+
+    //--- hard_clip
+    #[test]
+    fn hard_clip_clamps_to_ceiling() {
+        assert_eq!(hard_clip(1.5, 1.0), 1.0);
+        assert_eq!(hard_clip(-1.5, 1.0), -1.0);
+        assert_eq!(hard_clip(0.5, 1.0), 0.5);
+        assert_eq!(hard_clip(f32::NAN, 1.0), 0.0);
+        assert_eq!(hard_clip_db(1.0, -6.0), db_to_volt_f(-6.0));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Ceiling must be non-negative")]
+    fn hard_clip_panics_on_negative_ceiling_in_debug_builds() {
+        hard_clip(0.5, -1.0);
+    }
+
+    #[test]
+    #[cfg(not(debug_assertions))]
+    fn hard_clip_treats_invalid_ceilings_as_zero() {
+        for ceiling in [-1.0, f32::NAN] {
+            assert_eq!(hard_clip(0.5, ceiling), 0.0);
+            assert_eq!(hard_clip(-0.5, ceiling), 0.0);
+        }
+    }
+}
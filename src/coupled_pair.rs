@@ -0,0 +1,187 @@
+//! Two smoothed gains driven by a single stereo control (pan, balance or width).
+//!
+//! A stereo control maps one value onto two gains. `CoupledPair` computes both gains under a
+//! `PairLaw` and smooths them with two `TinySmoother`s sharing the same coefficient, so both
+//! gains move in lockstep and a control change doesn't click.
+//!
+//! # Examples
+//!
+//! ```
+//! use audio_utils::{CoupledPair, PairLaw, PanLaw};
+//!
+//! let mut panner = CoupledPair::new(48_000.0, 5.0, PairLaw::Pan(PanLaw::EqualPower), 0.0);
+//! let sample = 0.5f32;
+//!
+//! let (left_gain, right_gain) = panner.next(-0.5);
+//! let (left, right) = (sample * left_gain, sample * right_gain);
+//! ```
+
+use crate::pan::{equal_power, pan_gains, PanLaw};
+use crate::tiny_smoother::{beta_from_half_life, ms_to_samples, TinySmoother};
+use std::f32::consts::SQRT_2;
+
+/// How a `CoupledPair` maps its control value onto two gains.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PairLaw {
+    /// Pan position from -1.0 (left) to 1.0 (right) under the given pan law, yields
+    /// `(left, right)` gains, see `pan_gains()`.
+    Pan(PanLaw),
+    /// Balance from -1.0 (left) to 1.0 (right): 0 dB at center, the opposite channel is
+    /// attenuated linearly. Yields `(left, right)` gains.
+    Balance,
+    /// Stereo width from 0.0 (mono) over 1.0 (unchanged) to 2.0 (side only), yields
+    /// `(mid, side)` gains for mid/side processing. `mid² + side² = 2` everywhere, so the
+    /// energy of uncorrelated channels stays constant.
+    Width,
+}
+
+impl PairLaw {
+    /// Returns both gains for a control value, clamped to the law's range. `NaN` is passed
+    /// on, so the smoothers ignore it.
+    fn gains(self, control: f32) -> (f32, f32) {
+        if control.is_nan() {
+            return (f32::NAN, f32::NAN);
+        }
+        match self {
+            PairLaw::Pan(law) => pan_gains(control, law),
+            PairLaw::Balance => pan_gains(control, PanLaw::Linear),
+            PairLaw::Width => {
+                let (mid, side) = equal_power(control.clamp(0.0, 2.0) * 0.5);
+                (mid * SQRT_2, side * SQRT_2)
+            }
+        }
+    }
+}
+
+/// Two gains smoothed in lockstep, driven by one control value.
+pub struct CoupledPair {
+    law: PairLaw,
+    first: TinySmoother,
+    second: TinySmoother,
+}
+
+impl CoupledPair {
+    /// Creates a pair resting at the gains for `start_control`.
+    ///
+    /// # Parameters
+    /// * `sample_rate` - Sample rate in Hz
+    /// * `half_life_ms` - Time in milliseconds after which the gains have moved halfway
+    ///   toward new values (0.0 for no smoothing)
+    /// * `law` - The mapping from the control value to the gains
+    /// * `start_control` - Control value after creation and `reset()`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `half_life_ms` is negative or `start_control` is `NaN`.
+    pub fn new(
+        sample_rate: f32,
+        half_life_ms: f32,
+        law: PairLaw,
+        start_control: f32,
+    ) -> CoupledPair {
+        let beta = beta_from_half_life(ms_to_samples(sample_rate, half_life_ms));
+        let (first, second) = law.gains(start_control);
+        CoupledPair {
+            law,
+            first: TinySmoother::new(beta, first),
+            second: TinySmoother::new(beta, second),
+        }
+    }
+
+    /// Returns both smoothed gains for the next sample, moving toward the gains for `control`.
+    ///
+    /// The control is clamped to the law's range, `NaN` is ignored and keeps the current
+    /// gains.
+    ///
+    /// # Real-time safety
+    ///
+    /// Never panics and never allocates in release builds.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self, control: f32) -> (f32, f32) {
+        let (first, second) = self.law.gains(control);
+        (self.first.next(first), self.second.next(second))
+    }
+
+    /// Returns the law the pair was created with.
+    pub fn law(&self) -> PairLaw {
+        self.law
+    }
+
+    /// Jumps back to the gains for the start control without smoothing.
+    pub fn reset(&mut self) {
+        self.first.reset();
+        self.second.reset();
+    }
+}
+
+//--- Tests ---------------------------------------------------------------------------------------
+//
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LAWS: [PairLaw; 6] = [
+        PairLaw::Pan(PanLaw::EqualPower),
+        PairLaw::Pan(PanLaw::Compromise),
+        PairLaw::Pan(PanLaw::Linear),
+        PairLaw::Pan(PanLaw::ConstantSum),
+        PairLaw::Balance,
+        PairLaw::Width,
+    ];
+
+    /// Control value at which the law yields equal gains.
+    fn center(law: PairLaw) -> f32 {
+        if law == PairLaw::Width { 1.0 } else { 0.0 }
+    }
+
+    #[test]
+    fn center_gives_equal_gains() {
+        for law in LAWS {
+            let mut pair = CoupledPair::new(48_000.0, 5.0, law, center(law));
+            let (first, second) = pair.next(center(law));
+            assert_eq!(first, second, "{law:?}");
+        }
+        // balance and width leave the signal unchanged at center
+        assert_eq!(CoupledPair::new(48_000.0, 5.0, PairLaw::Balance, 0.0).next(0.0), (1.0, 1.0));
+        let (mid, side) = CoupledPair::new(48_000.0, 5.0, PairLaw::Width, 1.0).next(1.0);
+        assert!((mid - 1.0).abs() < 1e-6 && (side - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn sweeping_the_control_ramps_without_clicks() {
+        for law in LAWS {
+            let mut pair = CoupledPair::new(48_000.0, 1.0, law, -1.0);
+            let mut last = pair.next(-1.0);
+            // jump to the other end, then sweep back; a full jump of √2 moves ~0.02 per
+            // sample with a half-life of 48 samples
+            let sweep = (0..4800).map(|n| 2.0 - n as f32 / 1200.0);
+            let controls = std::iter::repeat_n(2.0, 4800).chain(sweep);
+            for control in controls {
+                let gains = pair.next(control);
+                assert!((gains.0 - last.0).abs() < 0.025, "{law:?}");
+                assert!((gains.1 - last.1).abs() < 0.025, "{law:?}");
+                last = gains;
+            }
+        }
+    }
+
+    #[test]
+    fn width_keeps_energy_constant() {
+        for width in [0.0, 0.3, 1.0, 1.7, 2.0] {
+            let (mid, side) = PairLaw::Width.gains(width);
+            assert!((mid * mid + side * side - 2.0).abs() < 1e-5, "width = {width}");
+        }
+        assert_eq!(PairLaw::Width.gains(0.0).1, 0.0);
+    }
+
+    #[test]
+    fn nan_control_keeps_gains_and_reset_returns_to_start() {
+        let mut pair = CoupledPair::new(48_000.0, 5.0, PairLaw::Balance, 0.0);
+        let gains = pair.next(1.0);
+        assert_eq!(pair.next(f32::NAN), gains);
+
+        pair.reset();
+        assert_eq!(pair.next(0.0), (1.0, 1.0));
+        assert_eq!(pair.law(), PairLaw::Balance);
+    }
+}
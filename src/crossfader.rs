@@ -0,0 +1,246 @@
+//! An equal-power crossfader between two signals (dry/wet, A/B) driven by a single control.
+//!
+//! The control position is smoothed with a `TinySmoother` before the equal-power curve is
+//! applied, so moving the control doesn't zipper and the gains stay power-complementary
+//! on every sample.
+//!
+//! For per-sample crossfades in a hot loop, `crossfade_gains()` looks the equal-power
+//! gains up in `CROSSFADE_LUT`, a quarter-cosine table, instead of calling `sin()`/`cos()`.
+//! The table output is bit-identical on every platform, while `sin()`/`cos()` depend on the
+//! platform's math library. Speed depends on the target: on x86_64 with a fast math library
+//! both are similarly fast (`examples/crossfade_benchmark.rs`), targets without fast
+//! transcendental functions benefit from the table.
+
+use crate::pan::equal_power;
+use crate::tiny_smoother::{beta_from_half_life, ms_to_samples, TinySmoother};
+
+/// Number of segments of `CROSSFADE_LUT`, the table has one more entry.
+const CROSSFADE_LUT_SEGMENTS: usize = 1024;
+
+/// Quarter cosine `cos(π/2 · i/1024)` for `i` in `0..=1024`, the equal-power gain of signal A
+/// at position `i/1024`. Signal B uses the mirrored entry, `sin(x) = cos(π/2 - x)`.
+///
+/// Generated at compile time by `crossfade_lut()`. Linear interpolation between the entries
+/// (see `crossfade_gains()`) is accurate to ~3e-7.
+pub const CROSSFADE_LUT: [f32; CROSSFADE_LUT_SEGMENTS + 1] = crossfade_lut();
+
+/// Computes `CROSSFADE_LUT`: `cos()` is not available in const context, so it is evaluated
+/// as a Taylor series in f64, which converges quickly on [0, π/2].
+const fn crossfade_lut() -> [f32; CROSSFADE_LUT_SEGMENTS + 1] {
+    let mut table = [0.0; CROSSFADE_LUT_SEGMENTS + 1];
+    let mut idx = 0;
+    while idx <= CROSSFADE_LUT_SEGMENTS {
+        let x = std::f64::consts::FRAC_PI_2 * idx as f64 / CROSSFADE_LUT_SEGMENTS as f64;
+        let mut sum = 1.0;
+        let mut term = 1.0;
+        let mut n = 1;
+        while n < 20 {
+            term *= -x * x / ((2 * n - 1) * (2 * n)) as f64;
+            sum += term;
+            n += 1;
+        }
+        table[idx] = sum as f32;
+        idx += 1;
+    }
+    // exact endpoints: unity gain at the start, silence at the end
+    table[0] = 1.0;
+    table[CROSSFADE_LUT_SEGMENTS] = 0.0;
+    table
+}
+
+/// Returns the equal-power `(a_gain, b_gain)` pair for a crossfade position, looked up in
+/// `CROSSFADE_LUT` with linear interpolation instead of calling `cos()`/`sin()`.
+///
+/// The gains deviate from `(cos(π/2 · position), sin(π/2 · position))` by less than 1e-6.
+/// Unlike `Crossfader::gains()`, the position is not smoothed.
+///
+/// # Arguments
+///
+/// * `position` - Position from 0.0 (A only) to 1.0 (B only), clamped to this range. `NaN`
+///   is treated as 0.0 (A only).
+///
+/// # Example
+/// ```
+/// use audio_utils::crossfade_gains;
+///
+/// let (dry, wet) = (0.5f32, -0.25f32);
+/// let (a_gain, b_gain) = crossfade_gains(0.5); // -3 dB each
+/// let mixed = dry * a_gain + wet * b_gain;
+///
+/// assert!((a_gain - std::f32::consts::FRAC_1_SQRT_2).abs() < 1e-6);
+/// assert_eq!(crossfade_gains(0.0), (1.0, 0.0));
+/// ```
+///
+/// # Real-time safety
+///
+/// Never panics and never allocates, for any input including `NaN` and infinities.
+#[inline]
+pub fn crossfade_gains(position: f32) -> (f32, f32) {
+    let position = if position.is_nan() {
+        0.0
+    } else {
+        position.clamp(0.0, 1.0)
+    };
+    let scaled = position * CROSSFADE_LUT_SEGMENTS as f32;
+    // the last segment also covers position 1.0, with a fraction of 1.0
+    let idx = (scaled as usize).min(CROSSFADE_LUT_SEGMENTS - 1);
+    let fraction = scaled - idx as f32;
+    // B reads the same segment mirrored: sin(x) = cos(π/2 - x)
+    let mirrored = CROSSFADE_LUT_SEGMENTS - idx;
+    let (a_low, a_high) = (CROSSFADE_LUT[idx], CROSSFADE_LUT[idx + 1]);
+    let (b_low, b_high) = (CROSSFADE_LUT[mirrored], CROSSFADE_LUT[mirrored - 1]);
+    (
+        a_low + (a_high - a_low) * fraction,
+        b_low + (b_high - b_low) * fraction,
+    )
+}
+
+/// Equal-power crossfader with a smoothed position.
+///
+/// # Example
+/// ```
+/// use audio_utils::Crossfader;
+///
+/// let mut crossfader = Crossfader::new(48_000.0, 10.0, 0.0);
+/// let (dry, wet) = (0.5f32, -0.25f32);
+///
+/// let (a_gain, b_gain) = crossfader.gains(1.0);
+/// let mixed = dry * a_gain + wet * b_gain;
+/// ```
+pub struct Crossfader {
+    smoother: TinySmoother,
+}
+
+impl Default for Crossfader {
+    /// Creates a crossfader starting at position 0.0 (signal A only) with the
+    /// smoothing time of `TinySmoother::default()`.
+    fn default() -> Crossfader {
+        Crossfader {
+            smoother: TinySmoother::default(),
+        }
+    }
+}
+
+impl Crossfader {
+    /// Creates a crossfader with the given smoothing time.
+    ///
+    /// # Parameters
+    /// * `sample_rate` - Sample rate in Hz
+    /// * `half_life_ms` - Time in milliseconds after which the position has moved halfway
+    ///   toward a new target (0.0 for no smoothing)
+    /// * `start_position` - Position after creation and `reset()`, 0.0 (A) to 1.0 (B)
+    ///
+    /// # Panics
+    ///
+    /// Panics if `half_life_ms` is negative or `start_position` is not finite.
+    pub fn new(sample_rate: f32, half_life_ms: f32, start_position: f32) -> Crossfader {
+        let beta = beta_from_half_life(ms_to_samples(sample_rate, half_life_ms));
+        Crossfader {
+            smoother: TinySmoother::new(beta, start_position.clamp(0.0, 1.0)),
+        }
+    }
+
+    /// Returns the `(a_gain, b_gain)` pair for the next sample.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - Target position from 0.0 (A only) to 1.0 (B only), clamped to this range.
+    ///   `NaN` is ignored and keeps the current position.
+    pub fn gains(&mut self, position: f32) -> (f32, f32) {
+        let position = self.smoother.next(position.clamp(0.0, 1.0));
+        equal_power(position)
+    }
+
+    /// Resets the position to the start position.
+    pub fn reset(&mut self) {
+        self.smoother.reset();
+    }
+}
+
+//--- Tests ---------------------------------------------------------------------------------------
+//
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crossfader_center_gives_equal_power_gains() {
+        let mut crossfader = Crossfader::new(48_000.0, 0.0, 0.5);
+        let (a, b) = crossfader.gains(0.5);
+        assert!((a - b).abs() < 1e-6);
+        assert!((a * a + b * b - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn crossfader_starts_at_start_position() {
+        let mut crossfader = Crossfader::new(48_000.0, 10.0, 0.0);
+        let (a, b) = crossfader.gains(0.0);
+        assert_eq!((a, b), (1.0, 0.0));
+    }
+
+    #[test]
+    fn crossfader_ramps_on_position_jump() {
+        let mut crossfader = Crossfader::new(48_000.0, 10.0, 0.0);
+
+        let (first_a, first_b) = crossfader.gains(1.0);
+        // no step: the first sample is still almost entirely A
+        assert!(first_a > 0.99 && first_b < 0.1);
+
+        let mut last_b = first_b;
+        for _ in 0..2000 {
+            let (a, b) = crossfader.gains(1.0);
+            assert!(b >= last_b);
+            assert!((a * a + b * b - 1.0).abs() < 1e-5);
+            last_b = b;
+        }
+        assert!(last_b > 0.99);
+    }
+
+    //--- crossfade_gains
+    #[test]
+    fn crossfade_gains_match_cos_and_sin_within_0_1_percent() {
+        for i in 0..=100_000 {
+            let position = i as f32 / 100_000.0;
+            let (a, b) = crossfade_gains(position);
+            let angle = position as f64 * std::f64::consts::FRAC_PI_2;
+            for (gain, exact) in [(a, angle.cos()), (b, angle.sin())] {
+                let error = (gain as f64 - exact).abs();
+                assert!(error < 1e-6, "position {position}: {gain} vs {exact}");
+                // relative error, where it is meaningful
+                assert!(exact < 1e-3 || error / exact < 1e-3, "position {position}");
+            }
+        }
+    }
+
+    #[test]
+    fn crossfade_gains_have_exact_endpoints_and_clamp() {
+        assert_eq!(crossfade_gains(0.0), (1.0, 0.0));
+        assert_eq!(crossfade_gains(1.0), (0.0, 1.0));
+        assert_eq!(crossfade_gains(-1.0), (1.0, 0.0));
+        assert_eq!(crossfade_gains(2.0), (0.0, 1.0));
+        assert_eq!(crossfade_gains(f32::NAN), (1.0, 0.0));
+        assert_eq!(crossfade_gains(f32::INFINITY), (0.0, 1.0));
+    }
+
+    #[test]
+    fn crossfade_gains_are_symmetric_and_power_complementary() {
+        for i in 0..=1000 {
+            let position = i as f32 / 1000.0;
+            let (a, b) = crossfade_gains(position);
+            let (mirrored_a, mirrored_b) = crossfade_gains(1.0 - position);
+            assert!((a - mirrored_b).abs() < 1e-6 && (b - mirrored_a).abs() < 1e-6);
+            assert!((a * a + b * b - 1.0).abs() < 1e-5, "position {position}");
+        }
+    }
+
+    #[test]
+    fn crossfader_can_be_reset() {
+        let mut crossfader = Crossfader::new(48_000.0, 10.0, 0.0);
+        for _ in 0..1000 {
+            crossfader.gains(1.0);
+        }
+        crossfader.reset();
+        let (a, _) = crossfader.gains(0.0);
+        assert_eq!(a, 1.0);
+    }
+}
@@ -22,6 +22,20 @@
 //! ```
 
 
+use crate::meter::peak_db;
+use std::ops::RangeInclusive;
+
+/// Minimum supported dB value
+pub(crate) const DB_VOLT_LOOKUP_MIN: i32 = -100;
+/// Maximum supported dB value
+pub(crate) const DB_VOLT_LOOKUP_MAX: i32 = 27;
+/// Distance between two neighbouring table entries in dB
+const DB_VOLT_LOOKUP_STEP: i32 = 1;
+/// Total size of the lookup table
+const DB_VOLT_LOOKUP_SIZE: usize =
+    ((DB_VOLT_LOOKUP_MAX - DB_VOLT_LOOKUP_MIN) / DB_VOLT_LOOKUP_STEP + 1) as usize;
+/// Offset to convert dB values to array indices
+const DB_VOLT_LOOKUP_OFFSET: usize = (-DB_VOLT_LOOKUP_MIN) as usize;
 
 /// A static lookup table mapping integer decibel values in the range -100 to +27 dB
 /// to corresponding linear voltage ratios (f32). The step size is exactly 1 dB,
@@ -31,159 +45,167 @@
 /// This table is intended for fast real-time conversion (e.g., from MIDI or UI sliders)
 /// without expensive floating-point operations like `powf`. No interpolation is required.
 ///
-/// Values are calculated using the formula: 10^(dB/20) and represented in scientific notation
-/// for maximum precision within f32 limits.
-const DB_VOLT_LOOKUP: [f32; 128] = [
-    // -100 dB to -91 dB
-    1.0000000e-05,
-    1.1220185e-05,
-    1.2589254e-05,
-    1.4125376e-05,
-    1.5848932e-05,
-    1.7782794e-05,
-    1.9952623e-05,
-    2.2387211e-05,
-    2.5118864e-05,
-    2.8183829e-05,
-    // -90 dB to -81 dB
-    3.1622777e-05,
-    3.5481339e-05,
-    3.9810717e-05,
-    4.4668359e-05,
-    5.0118723e-05,
-    5.6234133e-05,
-    6.3095734e-05,
-    7.0794578e-05,
-    7.9432823e-05,
-    8.9125094e-05,
-    // -80 dB to -71 dB
-    1.0000000e-04,
-    1.1220185e-04,
-    1.2589254e-04,
-    1.4125376e-04,
-    1.5848932e-04,
-    1.7782794e-04,
-    1.9952623e-04,
-    2.2387211e-04,
-    2.5118864e-04,
-    2.8183829e-04,
-    // -70 dB to -61 dB
-    3.1622777e-04,
-    3.5481339e-04,
-    3.9810717e-04,
-    4.4668359e-04,
-    5.0118723e-04,
-    5.6234133e-04,
-    6.3095734e-04,
-    7.0794578e-04,
-    7.9432823e-04,
-    8.9125094e-04,
-    // -60 dB to -51 dB
-    1.0000000e-03,
-    1.1220185e-03,
-    1.2589254e-03,
-    1.4125376e-03,
-    1.5848932e-03,
-    1.7782794e-03,
-    1.9952623e-03,
-    2.2387211e-03,
-    2.5118864e-03,
-    2.8183829e-03,
-    // -50 dB to -41 dB
-    3.1622777e-03,
-    3.5481339e-03,
-    3.9810717e-03,
-    4.4668359e-03,
-    5.0118723e-03,
-    5.6234133e-03,
-    6.3095734e-03,
-    7.0794578e-03,
-    7.9432823e-03,
-    8.9125094e-03,
-    // -40 dB to -31 dB
-    1.0000000e-02,
-    1.1220185e-02,
-    1.2589254e-02,
-    1.4125376e-02,
-    1.5848932e-02,
-    1.7782794e-02,
-    1.9952623e-02,
-    2.2387211e-02,
-    2.5118864e-02,
-    2.8183829e-02,
-    // -30 dB to -21 dB
-    3.1622777e-02,
-    3.5481339e-02,
-    3.9810717e-02,
-    4.4668359e-02,
-    5.0118723e-02,
-    5.6234133e-02,
-    6.3095734e-02,
-    7.0794578e-02,
-    7.9432823e-02,
-    8.9125094e-02,
-    // -20 dB to -11 dB
-    1.0000000e-01,
-    1.1220185e-01,
-    1.2589254e-01,
-    1.4125376e-01,
-    1.5848932e-01,
-    1.7782794e-01,
-    1.9952623e-01,
-    2.2387211e-01,
-    2.5118864e-01,
-    2.8183829e-01,
-    // -10 dB to -1 dB
-    3.1622777e-01,
-    3.5481339e-01,
-    3.9810717e-01,
-    4.4668359e-01,
-    5.0118723e-01,
-    5.6234133e-01,
-    6.3095734e-01,
-    7.0794578e-01,
-    7.9432823e-01,
-    8.9125094e-01,
-    // 0 dB to +9 dB
-    1.0000000e+00,
-    1.1220185e+00,
-    1.2589254e+00,
-    1.4125376e+00,
-    1.5848932e+00,
-    1.7782794e+00,
-    1.9952623e+00,
-    2.2387211e+00,
-    2.5118864e+00,
-    2.8183829e+00,
-    // +10 dB to +19 dB
-    3.1622777e+00,
-    3.5481339e+00,
-    3.9810717e+00,
-    4.4668359e+00,
-    5.0118723e+00,
-    5.6234133e+00,
-    6.3095734e+00,
-    7.0794578e+00,
-    7.9432823e+00,
-    8.9125094e+00,
-    // +20 dB to +27 dB
-    1.0000000e+01,
-    1.1220185e+01,
-    1.2589254e+01,
-    1.4125376e+01,
-    1.5848932e+01,
-    1.7782794e+01,
-    1.9952623e+01,
-    2.2387211e+01,
-];
-/// Offset to convert dB values to array indices
-const DB_VOLT_LOOKUP_OFFSET: usize = 100;
-/// Total size of the lookup table
-const DB_VOLT_LOOKUP_SIZE: usize = DB_VOLT_LOOKUP.len();
-/// Minimum supported dB value
-const DB_VOLT_LOOKUP_MIN: i32 = -(DB_VOLT_LOOKUP_OFFSET as i32);
-/// Maximum supported dB value
-const DB_VOLT_LOOKUP_MAX: i32 = DB_VOLT_LOOKUP_MIN + (DB_VOLT_LOOKUP_SIZE - 1) as i32;
+/// The table is generated at compile time by `db_volt_lookup()` from `DB_VOLT_LOOKUP_MIN`,
+/// `DB_VOLT_LOOKUP_MAX` and `DB_VOLT_LOOKUP_STEP`. The integer conversions index the table
+/// by dB, so a step other than 1 dB is rejected by the checks below until they learn to scale
+/// the index.
+const DB_VOLT_LOOKUP: [f32; DB_VOLT_LOOKUP_SIZE] = db_volt_lookup();
+
+// Compile-time guards for the relationships the conversions rely on.
+#[allow(clippy::modulo_one)] // `DB_VOLT_LOOKUP_STEP` is 1 for now
+const _: () = {
+    assert!(
+        DB_VOLT_LOOKUP_MIN == -100 && DB_VOLT_LOOKUP_MAX == 27,
+        "the documentation states -100..=27 dB, update it together with the range"
+    );
+    assert!(DB_VOLT_LOOKUP_STEP > 0);
+    assert!(
+        (DB_VOLT_LOOKUP_MAX - DB_VOLT_LOOKUP_MIN) % DB_VOLT_LOOKUP_STEP == 0,
+        "the range must be a whole number of steps"
+    );
+    assert!(
+        DB_VOLT_LOOKUP_SIZE
+            == ((DB_VOLT_LOOKUP_MAX - DB_VOLT_LOOKUP_MIN) / DB_VOLT_LOOKUP_STEP + 1) as usize
+    );
+    assert!(
+        DB_VOLT_LOOKUP_MAX
+            == DB_VOLT_LOOKUP_MIN + (DB_VOLT_LOOKUP_SIZE as i32 - 1) * DB_VOLT_LOOKUP_STEP
+    );
+    assert!(DB_VOLT_LOOKUP_STEP == 1, "the integer conversions index the table by dB");
+    assert!(DB_VOLT_LOOKUP_OFFSET as i32 == -DB_VOLT_LOOKUP_MIN);
+    assert!(DB_VOLT_LOOKUP[DB_VOLT_LOOKUP_OFFSET] == 1.0, "0 dB must be exact unity gain");
+    // precondition of the binary searches in `volt_to_db()` and friends
+    let mut idx = 1;
+    while idx < DB_VOLT_LOOKUP_SIZE {
+        assert!(DB_VOLT_LOOKUP[idx - 1] < DB_VOLT_LOOKUP[idx], "table must be strictly increasing");
+        idx += 1;
+    }
+};
+
+/// Computes the lookup table: `10^(dB/20)` for every `DB_VOLT_LOOKUP_STEP` from
+/// `DB_VOLT_LOOKUP_MIN` to `DB_VOLT_LOOKUP_MAX`.
+///
+/// `powf()` and `exp()` are not available in const context, so the value is split into a
+/// power of ten and a mantissa `10^(r/20)` with `r` in `0..20`, computed with a Taylor series
+/// in f64. Every mantissa is rounded to 8 significant digits. The former hand-written table
+/// rounded the mantissa of 3 dB up instead (1.4125376 for 1.41253754...), so the generated
+/// entries at -97, -77, ..., 23 dB may be 1 ulp below it; all other entries are identical.
+const fn db_volt_lookup() -> [f32; DB_VOLT_LOOKUP_SIZE] {
+    let mut table = [0.0; DB_VOLT_LOOKUP_SIZE];
+    let mut idx = 0;
+    while idx < DB_VOLT_LOOKUP_SIZE {
+        let db = DB_VOLT_LOOKUP_MIN + idx as i32 * DB_VOLT_LOOKUP_STEP;
+        let decade = db.div_euclid(20);
+        let mantissa = const_exp(db.rem_euclid(20) as f64 / 20.0 * std::f64::consts::LN_10);
+
+        // 8 significant digits, i.e. `mantissa * 1e7` rounded to an integer
+        let digits = (mantissa * 1e7 + 0.5) as i64 as f64;
+        let mut scale = 1.0;
+        let mut n = decade;
+        while n < 7 {
+            scale *= 10.0;
+            n += 1;
+        }
+        while n > 7 {
+            scale /= 10.0;
+            n -= 1;
+        }
+        table[idx] = (digits / scale) as f32;
+        idx += 1;
+    }
+    table
+}
+
+/// `e^x` for `0 <= x < ln(10)` as a const fn, exact to f64 precision.
+const fn const_exp(x: f64) -> f64 {
+    let mut sum = 1.0;
+    let mut term = 1.0;
+    let mut n = 1;
+    while n < 40 {
+        term *= x / n as f64;
+        sum += term;
+        n += 1;
+    }
+    sum
+}
+
+/// Lowest dB value supported by the conversions (-100 dB), lower values are clamped.
+pub const MIN_DB: i32 = DB_VOLT_LOOKUP_MIN;
+/// Highest dB value supported by the conversions (+27 dB), higher values are clamped.
+pub const MAX_DB: i32 = DB_VOLT_LOOKUP_MAX;
+/// Linear gain at `MIN_DB` (1e-5).
+pub const MIN_GAIN: f32 = DB_VOLT_LOOKUP[0];
+/// Linear gain at `MAX_DB` (~22.4).
+pub const MAX_GAIN: f32 = DB_VOLT_LOOKUP[DB_VOLT_LOOKUP_SIZE - 1];
+
+/// Returns the range of dB values supported by the conversions, `MIN_DB..=MAX_DB`.
+///
+/// Use it to validate or clamp user input instead of hardcoding -100 and 27.
+///
+/// # Example
+/// ```
+/// use audio_utils::{db_to_volt, supported_db_range};
+///
+/// // a UI slider reaching further than the table
+/// let slider_db = 40;
+/// let range = supported_db_range();
+/// let db = slider_db.clamp(*range.start(), *range.end());
+///
+/// assert_eq!(db, 27);
+/// assert_eq!(db_to_volt(db), db_to_volt(slider_db));
+/// ```
+pub const fn supported_db_range() -> RangeInclusive<i32> {
+    MIN_DB..=MAX_DB
+}
+
+/// Returns the `(db, gain)` pairs of the lookup table used by the conversions, ascending
+/// from `MIN_DB` to `MAX_DB`.
+///
+/// Useful for tooling, e.g. to render a fader scale or to validate another table against
+/// the exact values this crate uses.
+///
+/// # Example
+/// ```
+/// use audio_utils::{table_entries, MAX_DB};
+///
+/// let mut entries = table_entries();
+/// assert_eq!(entries.next(), Some((-100, 1e-5)));
+/// assert_eq!(entries.last().map(|(db, _)| db), Some(MAX_DB));
+/// ```
+pub fn table_entries() -> impl ExactSizeIterator<Item = (i32, f32)> {
+    DB_VOLT_LOOKUP.into_iter().enumerate().map(|(i, gain)| (i as i32 + MIN_DB, gain))
+}
+
+/// Guaranteed relative accuracy of the table-based conversions (0.01%).
+///
+/// Every entry returned by [`db_to_volt`] is within this relative distance of the exact
+/// value `10^(db/20)`. The interpolating [`db_to_volt_f`] is coarser between integer
+/// steps and is not covered by this guarantee.
+pub const CONVERSION_REL_TOLERANCE: f32 = 1e-4;
+
+/// Asserts that two gains agree within [`CONVERSION_REL_TOLERANCE`].
+///
+/// Meant for tests that check DSP code against the crate's guaranteed precision.
+///
+/// # Panics
+/// Panics if the relative difference between `actual` and `expected` exceeds
+/// `CONVERSION_REL_TOLERANCE`, or if either value is NaN.
+///
+/// # Example
+/// ```
+/// use audio_utils::{assert_gain_close, db_to_volt};
+///
+/// assert_gain_close(db_to_volt(-6), 0.5011872);
+/// ```
+#[track_caller]
+pub fn assert_gain_close(actual: f32, expected: f32) {
+    let diff = (actual - expected).abs();
+    let scale = actual.abs().max(expected.abs());
+    assert!(
+        diff <= scale * CONVERSION_REL_TOLERANCE,
+        "Gains differ by more than {CONVERSION_REL_TOLERANCE}: actual {actual}, expected {expected}"
+    );
+}
 
 
 /// Converts integer dB values in the range −100 to +27 into a linear voltage ratio
@@ -214,12 +236,56 @@ const DB_VOLT_LOOKUP_MAX: i32 = DB_VOLT_LOOKUP_MIN + (DB_VOLT_LOOKUP_SIZE - 1) a
 /// - The lookup table has a realtime factor of __14,202__ at a sample rate of 48 kHz,
 ///   meaning you can call it several thousand times per sample.
 ///
+/// # Compile time
+///
+/// `db_to_volt` is a `const fn`, so fixed gains can be evaluated at compile time:
+/// ```
+/// use audio_utils::db_to_volt;
+///
+/// const HALF_POWER: f32 = db_to_volt(-3);
+///
+/// assert_eq!(HALF_POWER, 7.0794578e-01);
+/// ```
+///
+/// # Real-time safety
+///
+/// Never panics and never allocates: out-of-range values are clamped before indexing.
+///
 #[inline(always)]
-pub fn db_to_volt(db: i32) -> f32 {
-    let db = db.clamp(DB_VOLT_LOOKUP_MIN, DB_VOLT_LOOKUP_MAX);
+pub const fn db_to_volt(db: i32) -> f32 {
+    // `Ord::clamp` is not usable in const context, hence the explicit comparisons.
+    let db = if db < DB_VOLT_LOOKUP_MIN {
+        DB_VOLT_LOOKUP_MIN
+    } else if db > DB_VOLT_LOOKUP_MAX {
+        DB_VOLT_LOOKUP_MAX
+    } else {
+        db
+    };
     let idx = (db + DB_VOLT_LOOKUP_OFFSET as i32) as usize;
     DB_VOLT_LOOKUP[idx]
 }
+
+/// Converts an integer dB value like `db_to_volt()`, but returns `None` instead of clamping
+/// when `db` is outside `MIN_DB..=MAX_DB`.
+///
+/// Useful during development to catch mapping bugs that `db_to_volt()` would hide by
+/// silently saturating.
+///
+/// # Example
+/// ```
+/// use audio_utils::{db_to_volt, db_to_volt_checked};
+///
+/// assert_eq!(db_to_volt_checked(-6), Some(db_to_volt(-6)));
+/// assert_eq!(db_to_volt_checked(40), None);
+/// ```
+#[inline]
+pub const fn db_to_volt_checked(db: i32) -> Option<f32> {
+    if db < DB_VOLT_LOOKUP_MIN || db > DB_VOLT_LOOKUP_MAX {
+        return None;
+    }
+    Some(DB_VOLT_LOOKUP[(db + DB_VOLT_LOOKUP_OFFSET as i32) as usize])
+}
+
 /// Syntactic sugar. Instead of `db_to_volt(decibels)` you can use `decibels.to_volt()`
 pub trait DbToVolt {
     fn to_volt(self) -> f32;
@@ -312,12 +378,317 @@ impl DbToVolt for f64 {
     }
 }
 
+/// Converts a slice of integer dB values into linear voltage ratios, see `db_to_volt()`.
+///
+/// With the `simd` feature enabled, the table lookups are vectorised (AVX2 gathers, 8 values
+/// per iteration) on CPUs that support it. The results are identical to `db_to_volt()`.
+///
+/// # Panics
+///
+/// Panics if `dbs` and `out` differ in length.
+///
+/// # Example
+/// ```
+/// use audio_utils::db_to_volt_slice;
+///
+/// let dbs = [-60, -20, 0];
+/// let mut volts = [0.0f32; 3];
+/// db_to_volt_slice(&dbs, &mut volts);
+///
+/// assert_eq!(volts, [0.001, 0.1, 1.0]);
+/// ```
+pub fn db_to_volt_slice(dbs: &[i32], out: &mut [f32]) {
+    assert_eq!(
+        dbs.len(),
+        out.len(),
+        "Input and output slices must have the same length"
+    );
+
+    #[cfg(feature = "simd")]
+    let done = crate::simd::db_to_volt_lookup(
+        &DB_VOLT_LOOKUP,
+        DB_VOLT_LOOKUP_MIN,
+        DB_VOLT_LOOKUP_MAX,
+        dbs,
+        out,
+    );
+    #[cfg(not(feature = "simd"))]
+    let done = 0;
+
+    for (volt, &db) in out[done..].iter_mut().zip(&dbs[done..]) {
+        *volt = db_to_volt(db);
+    }
+}
+
+/// Converts a fractional dB value into a linear voltage ratio by linear interpolation
+/// between the two neighbouring entries of the lookup table used by `db_to_volt()`.
+///
+/// Integer dB values yield exactly the same result as `db_to_volt()`. In between, the
+/// interpolation error stays below ~0.3% (about 0.03 dB), which is inaudible for gain changes.
+///
+/// # Arguments
+///
+/// * `db` - A decibel value, clamped to [-100.0, 27.0]. Non-finite values yield unity gain.
+///
+/// # Returns
+///
+/// * `f32` linear gain value in the range `[1e-5, ~22.4]`.
+///
+/// # Example
+/// ```
+/// use audio_utils::db_to_volt_f;
+///
+/// let gain_volt = db_to_volt_f(-6.5);
+///
+/// assert!(gain_volt > 0.473 && gain_volt < 0.475);
+/// ```
+///
+/// # Real-time safety
+///
+/// Never panics and never allocates, for any input including `NaN`, infinities and subnormals.
+#[inline]
+pub fn db_to_volt_f(db: f32) -> f32 {
+    if !db.is_finite() {
+        return 1.0; // Unity gain as safe default
+    }
+    let position = db.clamp(DB_VOLT_LOOKUP_MIN as f32, DB_VOLT_LOOKUP_MAX as f32)
+        - DB_VOLT_LOOKUP_MIN as f32;
+    let idx = position as usize;
+    if idx >= DB_VOLT_LOOKUP_SIZE - 1 {
+        return DB_VOLT_LOOKUP[DB_VOLT_LOOKUP_SIZE - 1];
+    }
+    let fraction = position - idx as f32;
+    let lo = DB_VOLT_LOOKUP[idx];
+    let hi = DB_VOLT_LOOKUP[idx + 1];
+    lo + (hi - lo) * fraction
+}
+
+/// Converts a fractional dB value into a linear voltage ratio exactly: `10^(db/20)`.
+///
+/// This is the accurate-but-slower counterpart of `db_to_volt_f()`: a single `exp2()`
+/// evaluation in f64 instead of a table lookup, so the result is correct to f32 precision at
+/// any fractional dB value, without the ~0.3% interpolation error. It is also not limited to
+/// the table range.
+///
+/// # Arguments
+///
+/// * `db` - A decibel value. `f32::NEG_INFINITY` yields 0.0, `NaN` yields unity gain; values
+///   above ~770 dB overflow to infinity.
+///
+/// # Example
+/// ```
+/// use audio_utils::{db_to_volt_exact, db_to_volt_f};
+///
+/// let exact = db_to_volt_exact(-6.5);
+///
+/// assert_eq!(exact, 0.47315126);
+/// assert!((db_to_volt_f(-6.5) / exact - 1.0).abs() < 3e-3);
+/// ```
+///
+/// # Real-time safety
+///
+/// Never panics and never allocates, for any input including `NaN`, infinities and subnormals.
+#[inline]
+pub fn db_to_volt_exact(db: f32) -> f32 {
+    if db.is_nan() {
+        return 1.0; // Unity gain as safe default
+    }
+    // 10^(db/20) = 2^(db * log2(10) / 20)
+    (db as f64 * (std::f64::consts::LOG2_10 / 20.0)).exp2() as f32
+}
+
+/// Maps a MIDI velocity (0–127) to a linear gain. The velocity is mapped linearly in the
+/// dB domain, velocity 0 to `min_db` and velocity 127 to `max_db`, rounded to the nearest
+/// integer dB and converted with `db_to_volt()`.
+///
+/// # Arguments
+///
+/// * `velocity` - MIDI velocity, values above 127 are clamped to 127.
+/// * `min_db` - The level in dB for velocity 0.
+/// * `max_db` - The level in dB for velocity 127.
+///
+/// # Returns
+///
+/// * `f32` linear gain value in the range `[1e-5, ~22.4]`.
+///
+/// # Example
+/// ```
+/// use audio_utils::{db_to_volt, velocity_to_gain};
+///
+/// assert_eq!(velocity_to_gain(127, -60, 0), 1.0);
+/// assert_eq!(velocity_to_gain(0, -60, 0), db_to_volt(-60));
+/// ```
+#[inline]
+pub fn velocity_to_gain(velocity: u8, min_db: i32, max_db: i32) -> f32 {
+    const MAX_VELOCITY: u8 = 127;
+    let velocity = velocity.min(MAX_VELOCITY) as f64;
+    // in f64, which holds any i32 and their difference exactly, while `max_db - min_db`
+    // may overflow i32
+    let span = max_db as f64 - min_db as f64;
+    let db = min_db as f64 + span * velocity / MAX_VELOCITY as f64;
+    db_to_volt(db.round() as i32)
+}
+
+/// Maps a MIDI CC value (0–127), e.g. CC 7 (channel volume), to a linear gain: CC 0 is
+/// silence (0.0) and CC 1–127 are mapped linearly in the dB domain from -60 dB to 0 dB
+/// (unity), converted with `db_to_volt_f()`. Each CC step is ~0.48 dB, fine enough to move
+/// without audible steps when smoothed.
+///
+/// For a custom dB range, see `velocity_to_gain()`.
+///
+/// # Arguments
+///
+/// * `cc` - MIDI CC value, values above 127 are clamped to 127.
+///
+/// # Example
+/// ```
+/// use audio_utils::{cc_to_gain, db_to_volt};
+///
+/// assert_eq!(cc_to_gain(0), 0.0);
+/// assert_eq!(cc_to_gain(1), db_to_volt(-60));
+/// assert_eq!(cc_to_gain(127), 1.0);
+/// ```
+#[inline]
+pub fn cc_to_gain(cc: u8) -> f32 {
+    const MAX_CC: u8 = 127;
+    const MIN_DB: f32 = -60.0;
+    if cc == 0 {
+        return 0.0;
+    }
+    let steps = (cc.min(MAX_CC) - 1) as f32;
+    db_to_volt_f(MIN_DB - MIN_DB * steps / (MAX_CC - 1) as f32)
+}
+
+/// The taper of a fader: how its position maps to dB, see `fader_to_db()`.
+///
+/// All laws map position 1.0 to 0 dB (unity) and position 0.0 to `f32::NEG_INFINITY` (off).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum FaderLaw {
+    /// Linear in dB from -60 dB just above 0.0 to 0 dB at 1.0, like `cc_to_gain()`: every
+    /// part of the travel covers the same dB range.
+    Linear,
+    /// `gain = position⁴`, i.e. `dB = 80·log10(position)`: -24 dB at the center, so the upper
+    /// half resolves the most used range near 0 dB more finely and the lower half fades out
+    /// all the way to silence.
+    #[default]
+    AudioTaper,
+    /// Linear in dB from `db` at `position` up to 0 dB at 1.0, like the upper scale of a
+    /// console fader. Below the knee the gain falls linearly to silence at 0.0.
+    ///
+    /// `position` is clamped to [0.0, 1.0] and `db` to at most 0 dB. A non-finite knee is
+    /// treated as a knee at 0.0, i.e. the whole travel is linear in dB.
+    Knee {
+        /// Fader position of the knee, e.g. 0.25
+        position: f32,
+        /// Level at the knee in dB, e.g. -40.0
+        db: f32,
+    },
+}
+
+/// Maps a fader position in [0.0, 1.0] to a level in dB using the taper `law`.
+///
+/// Position 1.0 is 0 dB and position 0.0 is `f32::NEG_INFINITY` for all laws, positions
+/// outside the range are clamped and `NaN` is treated as 0.0. For a linear gain, see
+/// `fader_to_gain()`.
+///
+/// # Example
+/// ```
+/// use audio_utils::{fader_to_db, FaderLaw};
+///
+/// assert_eq!(fader_to_db(1.0, FaderLaw::AudioTaper), 0.0);
+/// assert!((fader_to_db(0.5, FaderLaw::AudioTaper) + 24.08).abs() < 0.01);
+/// assert_eq!(fader_to_db(0.5, FaderLaw::Linear), -30.0);
+///
+/// let console = FaderLaw::Knee { position: 0.25, db: -40.0 };
+/// assert!((fader_to_db(0.625, console) + 20.0).abs() < 1e-4);
+/// ```
+///
+/// # Real-time safety
+///
+/// Never panics and never allocates, for any input including `NaN` and infinities.
+pub fn fader_to_db(position: f32, law: FaderLaw) -> f32 {
+    const LINEAR_MIN_DB: f32 = -60.0;
+    if position.is_nan() || position <= 0.0 {
+        return f32::NEG_INFINITY;
+    }
+    if position >= 1.0 {
+        return 0.0;
+    }
+    match law {
+        FaderLaw::Linear => LINEAR_MIN_DB * (1.0 - position),
+        FaderLaw::AudioTaper => 80.0 * position.log10(),
+        FaderLaw::Knee {
+            position: knee_position,
+            db: knee_db,
+        } => {
+            let (knee_position, knee_db) = if knee_position.is_finite() && knee_db.is_finite() {
+                (knee_position.clamp(0.0, 1.0), knee_db.min(0.0))
+            } else {
+                (0.0, 0.0)
+            };
+            if position >= knee_position {
+                let fraction = (position - knee_position) / (1.0 - knee_position);
+                knee_db * (1.0 - fraction)
+            } else {
+                knee_db + 20.0 * (position / knee_position).log10()
+            }
+        }
+    }
+}
+
+/// Maps a fader position in [0.0, 1.0] to a linear gain: `fader_to_db()` converted via
+/// `db_to_volt_f()`.
+///
+/// Position 0.0 yields 0.0 (silence), levels below -100 dB are clamped to `MIN_GAIN`.
+///
+/// # Example
+/// ```
+/// use audio_utils::{fader_to_gain, FaderLaw};
+///
+/// assert_eq!(fader_to_gain(0.0, FaderLaw::AudioTaper), 0.0);
+/// assert!((fader_to_gain(0.5, FaderLaw::AudioTaper) - 0.0625).abs() < 1e-4);
+/// assert!((fader_to_gain(1.0, FaderLaw::AudioTaper) - 1.0).abs() < 1e-6);
+/// ```
+///
+/// # Real-time safety
+///
+/// Never panics and never allocates, for any input including `NaN` and infinities.
+pub fn fader_to_gain(position: f32, law: FaderLaw) -> f32 {
+    let db = fader_to_db(position, law);
+    if db == f32::NEG_INFINITY {
+        return 0.0;
+    }
+    db_to_volt_f(db)
+}
+
+/// Binary search on `DB_VOLT_LOOKUP`: returns the index of the first entry that is
+/// not smaller than `gain_volt`.
+#[inline]
+fn upper_index(gain_volt: f32) -> usize {
+    let mut low = 0;
+    let mut high = DB_VOLT_LOOKUP_SIZE - 1;
+
+    while low < high {
+        let mid = (low + high) / 2;
+        if DB_VOLT_LOOKUP[mid] < gain_volt {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
 /// Converts a linear gain factor back into an approximate integer decibel value.
 /// Performs a binary search on the same precomputed `DB_VOLT_LOOKUP` table used by `db_to_volt()`.
 ///
 /// This function guarantees that `volt_to_db(db_to_volt(given_db))` yields the `given_db` value
 /// (the round trip is stable).
 ///
+/// The gain is rounded to the nearest dB value in the log domain: the boundary between two
+/// table entries is their geometric mean (e.g. -6.5 dB between -7 and -6 dB), not their linear
+/// midpoint, which would bias the rounding toward the lower dB value.
+///
 /// # Arguments
 ///
 /// * `gain_volt` - A linear gain value (f32). Values below the minimum map to -100 dB. Values above
@@ -346,66 +717,203 @@ impl DbToVolt for f64 {
 /// - The lookup table iteration has a realtime factor of __1865__ at a sample rate of 48 kHz, on a
 ///   small Intel® Core™ i5-7200U CPU system.
 ///   Meaning you can call it several hundred times per sample.
+///
+/// # Real-time safety
+///
+/// Never panics and never allocates, for any input including `NaN`, infinities and subnormals.
 pub fn volt_to_db(gain_volt: f32) -> i32 {
+    (nearest_index(gain_volt) as i32) + DB_VOLT_LOOKUP_MIN
+}
+
+/// Converts a slice of linear gains into integer dB values, see `volt_to_db()`, e.g. for a
+/// bank of meters updated once per GUI frame.
+///
+/// # Panics
+///
+/// Panics if `gains` and `out` differ in length.
+///
+/// # Example
+/// ```
+/// use audio_utils::volt_to_db_slice;
+///
+/// let levels = [0.001, 0.1, 1.0];
+/// let mut dbs = [0i32; 3];
+/// volt_to_db_slice(&levels, &mut dbs);
+///
+/// assert_eq!(dbs, [-60, -20, 0]);
+/// ```
+pub fn volt_to_db_slice(gains: &[f32], out: &mut [i32]) {
+    assert_eq!(
+        gains.len(),
+        out.len(),
+        "Input and output slices must have the same length"
+    );
+    for (db, &gain) in out.iter_mut().zip(gains) {
+        *db = volt_to_db(gain);
+    }
+}
+
+/// Returns the index of the table entry nearest to `gain_volt` in the log domain, see
+/// `volt_to_db()`.
+#[inline]
+fn nearest_index(gain_volt: f32) -> usize {
     // Decibels are defined as 10*log(gain^2). Because of the squaring, volt_to_db(g) = volt_to_db(-g).
     let gain_volt = gain_volt.abs();
 
     // shortcut (and clamping) for small values
     if gain_volt <= DB_VOLT_LOOKUP[0] {
-        return DB_VOLT_LOOKUP_MIN;
+        return 0;
     }
 
     // shortcut (and clamping) for large values
     if gain_volt >= DB_VOLT_LOOKUP[DB_VOLT_LOOKUP_SIZE - 1] {
-        return DB_VOLT_LOOKUP_MAX;
+        return DB_VOLT_LOOKUP_SIZE - 1;
     }
 
-    let mut low = 0;
-    let mut high = DB_VOLT_LOOKUP_SIZE - 1;
-
-    while low < high {
-        let mid = (low + high) / 2;
-        if DB_VOLT_LOOKUP[mid] < gain_volt {
-            low = mid + 1;
-        } else {
-            high = mid;
-        }
-    }
+    let low = upper_index(gain_volt);
 
-    let idx = if low > 0 {
-        // Pick the closer of low and low-1
-        let lo = DB_VOLT_LOOKUP[low];
-        let hi = DB_VOLT_LOOKUP[low - 1];
-        if (gain_volt - hi).abs() < (gain_volt - lo).abs() {
+    if low > 0 {
+        // Pick the closer of low and low-1 in the ratio (log) domain, i.e. the rounding
+        // boundary is the geometric mean of both entries, not their linear midpoint.
+        let lower = DB_VOLT_LOOKUP[low - 1];
+        let upper = DB_VOLT_LOOKUP[low];
+        if gain_volt / lower < upper / gain_volt {
             low - 1
         } else {
             low
         }
     } else {
         low
-    };
+    }
+}
 
+/// Converts a linear gain factor into the integer dB value of the nearest table entry that
+/// is not louder, i.e. rounds down instead of to the nearest value like `volt_to_db()`.
+///
+/// Useful for conservative displays, e.g. a limiter threshold that must never show a louder
+/// value than it actually is. Values outside the table are clamped to [-100, 27] dB, `NaN`
+/// maps to -100 dB.
+///
+/// # Example
+/// ```
+/// use audio_utils::{volt_to_db, volt_to_db_ceil, volt_to_db_floor};
+///
+/// let gain = 0.49; // -6.2 dB
+///
+/// assert_eq!(volt_to_db(gain), -6);
+/// assert_eq!(volt_to_db_floor(gain), -7);
+/// assert_eq!(volt_to_db_ceil(gain), -6);
+/// ```
+///
+/// # Real-time safety
+///
+/// Never panics and never allocates, for any input including `NaN`, infinities and subnormals.
+pub fn volt_to_db_floor(gain_volt: f32) -> i32 {
+    let gain_volt = gain_volt.abs();
+    if gain_volt.is_nan() || gain_volt <= DB_VOLT_LOOKUP[0] {
+        return DB_VOLT_LOOKUP_MIN;
+    }
+    let idx = upper_index(gain_volt);
+    // the entry found is not smaller than the gain: step down unless it is an exact hit
+    let idx = if DB_VOLT_LOOKUP[idx] > gain_volt { idx - 1 } else { idx };
     (idx as i32) + DB_VOLT_LOOKUP_MIN
 }
-/// Syntactic sugar. Instead of `gain_to_db(gain)` you can use `gain.to_db()`
-pub trait VoltToDb {
-    fn to_db(self) -> i32;
+
+/// Converts a linear gain factor into the integer dB value of the nearest table entry that
+/// is not quieter, i.e. rounds up instead of to the nearest value like `volt_to_db()`.
+///
+/// Values outside the table are clamped to [-100, 27] dB, `NaN` maps to -100 dB. See
+/// `volt_to_db_floor()` for an example.
+///
+/// # Real-time safety
+///
+/// Never panics and never allocates, for any input including `NaN`, infinities and subnormals.
+pub fn volt_to_db_ceil(gain_volt: f32) -> i32 {
+    let gain_volt = gain_volt.abs();
+    if gain_volt.is_nan() {
+        return DB_VOLT_LOOKUP_MIN;
+    }
+    // `upper_index()` stops at the last entry for values above the table
+    (upper_index(gain_volt) as i32) + DB_VOLT_LOOKUP_MIN
 }
-impl VoltToDb for f32 {
-    /// Converts a gain value given as a f32 into a decibel value.
-    /// Note: Returns the nearest integer dB value from the lookup table.
-    ///
-    /// # Example
-    /// ```
-    /// use audio_utils::VoltToDb;
-    ///
-    /// let gain:f32 = 0.001;
-    /// let decibels = gain.to_db();
-    ///
-    /// assert_eq!(decibels, -60);
-    /// ```
-    ///
-    #[inline]
+
+/// Snaps a linear gain factor to the nearest table entry and returns both its integer dB
+/// value and its exact gain.
+///
+/// Equivalent to `(volt_to_db(g), db_to_volt(volt_to_db(g)))` with a single binary search,
+/// e.g. for a fader snapping to integer dB. Like `volt_to_db()`, the sign of `gain_volt` is
+/// ignored, values outside the table are clamped and `NaN` maps to -100 dB.
+///
+/// # Example
+/// ```
+/// use audio_utils::{db_to_volt, snap_gain};
+///
+/// let (db, gain) = snap_gain(0.49);
+///
+/// assert_eq!(db, -6);
+/// assert_eq!(gain, db_to_volt(-6));
+/// ```
+///
+/// # Real-time safety
+///
+/// Never panics and never allocates, for any input including `NaN`, infinities and subnormals.
+pub fn snap_gain(gain_volt: f32) -> (i32, f32) {
+    let idx = nearest_index(gain_volt);
+    ((idx as i32) + DB_VOLT_LOOKUP_MIN, DB_VOLT_LOOKUP[idx])
+}
+
+/// Returns the gain at which the readout of `volt_to_db()` flips from `db` to `db + 1`.
+///
+/// This is the geometric mean of both table entries, the rounding boundary of
+/// `volt_to_db()`, adjusted to the f32 grid: the result is the lowest gain that reads
+/// `db + 1`, so a GUI can draw fader detents exactly where the readout changes. `db` is
+/// clamped to [-100, 26].
+///
+/// # Example
+/// ```
+/// use audio_utils::{db_boundary_gain, volt_to_db};
+///
+/// let boundary = db_boundary_gain(-7); // -6.5 dB
+///
+/// assert_eq!(volt_to_db(boundary), -6);
+/// assert_eq!(volt_to_db(boundary.next_down()), -7);
+/// ```
+pub fn db_boundary_gain(db: i32) -> f32 {
+    let idx = (db.clamp(DB_VOLT_LOOKUP_MIN, DB_VOLT_LOOKUP_MAX - 1) - DB_VOLT_LOOKUP_MIN) as usize;
+    let geometric_mean = (DB_VOLT_LOOKUP[idx] as f64 * DB_VOLT_LOOKUP[idx + 1] as f64).sqrt();
+    // the rounding in `nearest_index()` compares in f32, so the flip may be an ulp away
+    let mut boundary = geometric_mean as f32;
+    while nearest_index(boundary) <= idx {
+        boundary = boundary.next_up();
+    }
+    while nearest_index(boundary.next_down()) > idx {
+        boundary = boundary.next_down();
+    }
+    boundary
+}
+
+/// Syntactic sugar. Instead of `volt_to_db(gain)` you can use `gain.to_db()`.
+///
+/// Always returns whole dB. For a choice between whole and fractional dB by output type,
+/// see `gain_to_db()` and `Gain::to_db()`.
+pub trait VoltToDb {
+    fn to_db(self) -> i32;
+}
+impl VoltToDb for f32 {
+    /// Converts a gain value given as a f32 into a decibel value.
+    /// Note: Returns the nearest integer dB value from the lookup table.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::VoltToDb;
+    ///
+    /// let gain:f32 = 0.001;
+    /// let decibels = gain.to_db();
+    ///
+    /// assert_eq!(decibels, -60);
+    /// ```
+    ///
+    #[inline]
     fn to_db(self) -> i32 {
         if !self.is_finite() {
             return -100; // Minimum as safe default
@@ -435,6 +943,524 @@ impl VoltToDb for f64 {
         volt_to_db(self as f32)
     }
 }
+impl VoltToDb for i16 {
+    /// Converts a 16-bit PCM sample into a decibel value relative to full scale, where
+    /// `i16::MAX` is 0 dBFS (symmetric full scale: the sample is divided by `i16::MAX`).
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::VoltToDb;
+    ///
+    /// assert_eq!(i16::MAX.to_db(), 0);
+    /// assert_eq!((-16384i16).to_db(), -6);
+    /// ```
+    #[inline]
+    fn to_db(self) -> i32 {
+        volt_to_db(self.to_f32_sample())
+    }
+}
+impl VoltToDb for i32 {
+    /// Converts a 32-bit PCM sample into a decibel value relative to full scale, where
+    /// `i32::MAX` is 0 dBFS (symmetric full scale: the sample is divided by `i32::MAX`).
+    ///
+    /// Note that `DbToVolt` for `i32` takes a dB value instead, since integer dB values are
+    /// the input of `db_to_volt()`.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::VoltToDb;
+    ///
+    /// assert_eq!(i32::MAX.to_db(), 0);
+    /// ```
+    #[inline]
+    fn to_db(self) -> i32 {
+        volt_to_db(self.to_f32_sample())
+    }
+}
+
+/// Integer PCM samples as delivered by decoders and audio interfaces.
+///
+/// Full scale is symmetric: the sample is divided by the type's `MAX`, so `MAX` maps to 1.0
+/// (0 dBFS) and `MIN` to slightly below -1.0.
+pub trait PcmSample {
+    /// Returns the sample normalised to full scale, i.e. as f32 sample in [-1.0, 1.0].
+    fn to_f32_sample(self) -> f32;
+}
+impl PcmSample for i16 {
+    #[inline]
+    fn to_f32_sample(self) -> f32 {
+        self as f32 / i16::MAX as f32
+    }
+}
+impl PcmSample for i32 {
+    #[inline]
+    fn to_f32_sample(self) -> f32 {
+        // f64, since f32 can't hold all 32-bit values
+        (self as f64 / i32::MAX as f64) as f32
+    }
+}
+
+/// Converts an integer PCM sample into a fractional level in dBFS, normalised to full scale
+/// as described in [`PcmSample`]. A zero sample returns `f32::NEG_INFINITY`, see
+/// `sample_to_dbfs()`.
+///
+/// # Example
+/// ```
+/// use audio_utils::pcm_to_dbfs;
+///
+/// assert_eq!(pcm_to_dbfs(i16::MAX), 0.0);
+/// assert!((pcm_to_dbfs(-16384i16) + 6.02).abs() < 0.01);
+/// assert_eq!(pcm_to_dbfs(0i32), f32::NEG_INFINITY);
+/// ```
+#[inline]
+pub fn pcm_to_dbfs<T: PcmSample>(sample: T) -> f32 {
+    sample_to_dbfs(sample.to_f32_sample())
+}
+
+/// Converts a gain of any type implementing `VoltToDb` into an integer decibel value,
+/// saving the cast to f32: `f64` gains and integer PCM samples (relative to full scale)
+/// are accepted as they are.
+///
+/// # Example
+/// ```
+/// use audio_utils::volt_to_db_any;
+///
+/// assert_eq!(volt_to_db_any(0.001f64), -60);
+/// assert_eq!(volt_to_db_any(0.001f32), -60);
+/// assert_eq!(volt_to_db_any(i16::MAX), 0);
+/// ```
+#[inline]
+pub fn volt_to_db_any<T: VoltToDb>(gain: T) -> i32 {
+    gain.to_db()
+}
+
+/// Output type of `gain_to_db()` and `Gain::to_db()`, selecting the conversion by type:
+///
+/// * `i32` - the nearest whole dB value, like `volt_to_db()`
+/// * `f32` - the interpolated fractional dB value, like `volt_to_db_f()`
+pub trait ToDb {
+    /// Converts a linear gain factor into a dB value of this type.
+    fn from_gain(gain_volt: f32) -> Self;
+}
+impl ToDb for i32 {
+    #[inline]
+    fn from_gain(gain_volt: f32) -> i32 {
+        gain_volt.to_db()
+    }
+}
+impl ToDb for f32 {
+    #[inline]
+    fn from_gain(gain_volt: f32) -> f32 {
+        volt_to_db_f(gain_volt)
+    }
+}
+
+/// Converts a linear gain factor into decibels, as whole dB (`i32`) or fractional dB (`f32`)
+/// depending on the requested output type, see `ToDb`.
+///
+/// # Example
+/// ```
+/// use audio_utils::gain_to_db;
+///
+/// assert_eq!(gain_to_db::<i32>(0.49), -6);
+/// assert!((gain_to_db::<f32>(0.49) + 6.196).abs() < 0.01);
+/// ```
+///
+/// # Real-time safety
+///
+/// Never panics and never allocates, like `volt_to_db()` and `volt_to_db_f()`.
+#[inline]
+pub fn gain_to_db<T: ToDb>(gain_volt: f32) -> T {
+    T::from_gain(gain_volt)
+}
+
+/// Converts a linear gain factor into a fractional decibel value.
+/// It is the inverse of `db_to_volt_f()`: the gain is located in the lookup table by binary
+/// search and the dB value is interpolated linearly between the two bracketing entries.
+///
+/// # Arguments
+///
+/// * `gain_volt` - A linear gain value (f32). Values below the minimum map to -100 dB. Values above
+///   maximum map to +27 dB. `NaN` maps to -100 dB.
+///
+/// # Returns
+///
+/// * `f32` decibel value in the range `[-100.0, 27.0]`, or `f32::NEG_INFINITY` for a gain of 0.0
+///
+/// # Silence
+///
+/// Unlike the integer `volt_to_db()`, which maps everything at or below 1e-5 to -100 dB, this
+/// function distinguishes true silence from a quiet signal: `0.0` maps to `f32::NEG_INFINITY`,
+/// while tiny but non-zero values are clamped to -100 dB.
+///
+/// ```
+/// use audio_utils::volt_to_db_f;
+///
+/// assert_eq!(volt_to_db_f(0.0), f32::NEG_INFINITY);
+/// assert_eq!(volt_to_db_f(1e-6), -100.0);
+/// ```
+///
+/// # Example
+/// ```
+/// use audio_utils::{db_to_volt_f, volt_to_db_f};
+///
+/// let decibels = volt_to_db_f(db_to_volt_f(-6.5));
+///
+/// assert!((decibels + 6.5).abs() < 1e-4);
+/// ```
+///
+/// # Real-time safety
+///
+/// Never panics and never allocates, like `volt_to_db()`.
+pub fn volt_to_db_f(gain_volt: f32) -> f32 {
+    let gain_volt = gain_volt.abs();
+
+    // true silence
+    if gain_volt == 0.0 {
+        return f32::NEG_INFINITY;
+    }
+
+    // shortcut (and clamping) for small values and NaN
+    if gain_volt.is_nan() || gain_volt <= DB_VOLT_LOOKUP[0] {
+        return DB_VOLT_LOOKUP_MIN as f32;
+    }
+
+    // shortcut (and clamping) for large values
+    if gain_volt >= DB_VOLT_LOOKUP[DB_VOLT_LOOKUP_SIZE - 1] {
+        return DB_VOLT_LOOKUP_MAX as f32;
+    }
+
+    // `gain_volt` lies in (DB_VOLT_LOOKUP[high - 1], DB_VOLT_LOOKUP[high]]
+    let high = upper_index(gain_volt);
+    let lo = DB_VOLT_LOOKUP[high - 1];
+    let hi = DB_VOLT_LOOKUP[high];
+    let fraction = (gain_volt - lo) / (hi - lo);
+
+    (high - 1) as f32 + fraction + DB_VOLT_LOOKUP_MIN as f32
+}
+
+/// Converts a slice of linear gains into fractional dB values, see `volt_to_db_f()`, e.g. for
+/// the bins of a spectrum display.
+///
+/// # Panics
+///
+/// Panics if `gains` and `out` differ in length.
+///
+/// # Example
+/// ```
+/// use audio_utils::volt_to_db_slice_f;
+///
+/// let levels = [0.0, 0.001, 1.0];
+/// let mut dbs = [0.0f32; 3];
+/// volt_to_db_slice_f(&levels, &mut dbs);
+///
+/// assert_eq!(dbs, [f32::NEG_INFINITY, -60.0, 0.0]);
+/// ```
+pub fn volt_to_db_slice_f(gains: &[f32], out: &mut [f32]) {
+    assert_eq!(
+        gains.len(),
+        out.len(),
+        "Input and output slices must have the same length"
+    );
+    for (db, &gain) in out.iter_mut().zip(gains) {
+        *db = volt_to_db_f(gain);
+    }
+}
+
+/// The convention that relates a decibel value to a linear ratio.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DbConvention {
+    /// `dB = 20·log10(ratio)` for amplitude-like quantities: sample values, voltages, gains.
+    /// This is the convention of all other functions in this module.
+    #[default]
+    Amplitude,
+    /// `dB = 10·log10(ratio)` for power-like quantities: energy, intensity, squared samples,
+    /// e.g. when summing uncorrelated signals.
+    Power,
+}
+
+/// Converts a fractional dB value into a linear ratio using the given `convention`.
+///
+/// Both conventions use the lookup table via `db_to_volt_f()`: a power ratio is the square of
+/// an amplitude ratio, so the power convention looks up `2·db`. Therefore it covers the range
+/// [-50, 13.5] dB, values outside are clamped.
+///
+/// # Example
+/// ```
+/// use audio_utils::{db_to_volt_with, DbConvention};
+///
+/// let amplitude = db_to_volt_with(-3.0, DbConvention::Amplitude); // ≈ 0.708
+/// let power = db_to_volt_with(-3.0, DbConvention::Power);         // ≈ 0.5
+/// ```
+#[inline]
+pub fn db_to_volt_with(db: f32, convention: DbConvention) -> f32 {
+    match convention {
+        DbConvention::Amplitude => db_to_volt_f(db),
+        DbConvention::Power => db_to_volt_f(2.0 * db),
+    }
+}
+
+/// Converts a linear ratio into a fractional dB value using the given `convention`,
+/// see `volt_to_db_f()`.
+///
+/// # Example
+/// ```
+/// use audio_utils::{volt_to_db_with, DbConvention};
+///
+/// assert_eq!(volt_to_db_with(0.01, DbConvention::Amplitude), -40.0);
+/// assert_eq!(volt_to_db_with(0.01, DbConvention::Power), -20.0);
+/// ```
+#[inline]
+pub fn volt_to_db_with(gain: f32, convention: DbConvention) -> f32 {
+    match convention {
+        DbConvention::Amplitude => volt_to_db_f(gain),
+        DbConvention::Power => volt_to_db_f(gain) / 2.0,
+    }
+}
+
+/// Converts a level in dBFS (decibels relative to full scale) into a linear gain.
+///
+/// 0 dBFS corresponds to a sample value of ±1.0. The lookup table is already referenced to
+/// unity, so this is `db_to_volt_f()` under a clearly named dBFS surface.
+///
+/// # Example
+/// ```
+/// use audio_utils::dbfs_to_gain;
+///
+/// assert_eq!(dbfs_to_gain(0.0), 1.0);
+/// assert_eq!(dbfs_to_gain(-20.0), 0.1);
+/// ```
+#[inline]
+pub fn dbfs_to_gain(dbfs: f32) -> f32 {
+    db_to_volt_f(dbfs)
+}
+
+/// Converts a linear gain into dBFS (decibels relative to full scale), see `volt_to_db_f()`.
+///
+/// A gain of 1.0 corresponds to 0 dBFS. Silence (0.0) returns `f32::NEG_INFINITY`.
+///
+/// # Example
+/// ```
+/// use audio_utils::gain_to_dbfs;
+///
+/// assert_eq!(gain_to_dbfs(1.0), 0.0);
+/// assert!((gain_to_dbfs(0.5) + 6.02).abs() < 0.01);
+/// ```
+#[inline]
+pub fn gain_to_dbfs(gain: f32) -> f32 {
+    volt_to_db_f(gain)
+}
+
+/// Returns the headroom of a peak level to 0 dBFS in dB, e.g. for a mastering readout:
+/// `-volt_to_db_f(peak_gain)`.
+///
+/// Peaks below full scale report positive headroom, peaks above full scale report the
+/// overage as negative headroom. The result follows the table range, i.e. it is clamped to
+/// [-27, 100] dB, except for silence (0.0), which has `f32::INFINITY` headroom. The sign of
+/// the peak is ignored.
+///
+/// # Example
+/// ```
+/// use audio_utils::headroom_db;
+///
+/// assert!((headroom_db(0.5) - 6.02).abs() < 0.01);
+/// assert_eq!(headroom_db(1.0), 0.0);
+/// assert!(headroom_db(1.2) < 0.0); // clipping by ~1.6 dB
+/// ```
+#[inline]
+pub fn headroom_db(peak_gain: f32) -> f32 {
+    -volt_to_db_f(peak_gain)
+}
+
+/// Returns the gain that brings the peak of `buffer` to `target_dbfs`, e.g. to normalize a
+/// recording to -1 dBFS: `db_to_volt_f(target_dbfs - peak_db(buffer))`.
+///
+/// Both conversions use the lookup table, so the new peak lands within ~0.05 dB of the
+/// target, and the gain is limited to the table range of [-100, 27] dB: a buffer more than
+/// 27 dB below the target is only raised by 27 dB.
+///
+/// # Returns
+///
+/// * The gain factor to multiply all samples with
+/// * 1.0 for a silent (below -100 dB) or empty buffer, and for a non-finite `target_dbfs`
+///
+/// # Example
+/// ```
+/// use audio_utils::{peak_db, peak_normalize_gain};
+///
+/// let mut buffer = [0.25f32, -0.5, 0.125];
+/// let gain = peak_normalize_gain(&buffer, -1.0);
+/// buffer.iter_mut().for_each(|sample| *sample *= gain);
+///
+/// assert!((peak_db(&buffer) + 1.0).abs() < 0.05);
+/// assert_eq!(peak_normalize_gain(&[0.0; 16], -1.0), 1.0);
+/// ```
+pub fn peak_normalize_gain(buffer: &[f32], target_dbfs: f32) -> f32 {
+    let peak = peak_db(buffer);
+    if peak <= DB_VOLT_LOOKUP_MIN as f32 {
+        return 1.0;
+    }
+    db_to_volt_f(target_dbfs - peak)
+}
+
+/// Returns the level of `a_gain` relative to `b_gain` in dB: `20 * log10(|a| / |b|)`, e.g.
+/// to match the loudness of a processed signal to its input.
+///
+/// Computed exactly in f64, not via the table, so the result isn't limited to the table
+/// range. The signs are ignored.
+///
+/// # Returns
+///
+/// * `f32::INFINITY` if only `b_gain` is 0.0, `f32::NEG_INFINITY` if only `a_gain` is 0.0
+/// * 0.0 if both are 0.0, or if a gain is `NaN` or both are infinite
+///
+/// # Example
+/// ```
+/// use audio_utils::db_difference;
+///
+/// assert!((db_difference(0.5, 0.25) - 6.02).abs() < 0.01);
+/// assert!((db_difference(0.25, 0.5) + 6.02).abs() < 0.01);
+/// assert_eq!(db_difference(0.3, 0.3), 0.0);
+/// ```
+pub fn db_difference(a_gain: f32, b_gain: f32) -> f32 {
+    let ratio = (a_gain as f64 / b_gain as f64).abs();
+    if ratio.is_nan() {
+        return 0.0; // NaN, 0/0 and inf/inf: no meaningful difference
+    }
+    (20.0 * ratio.log10()) as f32
+}
+
+/// Returns the level of a single sample in dBFS. The sign of the sample is ignored,
+/// so `1.0` and `-1.0` are both at 0 dBFS.
+///
+/// # Example
+/// ```
+/// use audio_utils::sample_to_dbfs;
+///
+/// assert_eq!(sample_to_dbfs(-1.0), 0.0);
+/// ```
+#[inline]
+pub fn sample_to_dbfs(sample: f32) -> f32 {
+    gain_to_dbfs(sample.abs())
+}
+
+/// Applies a trim of `delta_db` to a linear `gain`: `gain * db_to_volt_f(delta_db)`.
+///
+/// The gain is multiplied directly instead of taking the lossy round trip through
+/// `volt_to_db()`, adding and `db_to_volt()`, so it is not rounded to a table entry: only
+/// the factor for `delta_db` is interpolated from the table. A non-finite `delta_db` leaves
+/// the gain unchanged.
+///
+/// # Example
+/// ```
+/// use audio_utils::gain_add_db;
+///
+/// let boosted = gain_add_db(0.5, 6.0);
+///
+/// assert!((boosted - 1.0).abs() < 0.01);
+/// ```
+#[inline]
+pub fn gain_add_db(gain: f32, delta_db: f32) -> f32 {
+    gain * db_to_volt_f(delta_db)
+}
+
+/// Returns the total level in dB of chained gain stages (e.g. input trim, fader and makeup
+/// gain): the product of the linear `gains`, converted via `volt_to_db_f()`.
+///
+/// The total is clamped to [-100.0, 27.0] dB like `volt_to_db_f()`, a stage of 0.0 (muted)
+/// yields `f32::NEG_INFINITY`. No stages at all yield 0 dB.
+///
+/// # Example
+/// ```
+/// use audio_utils::{db_to_volt_f, gain_sum_db};
+///
+/// let stages = [db_to_volt_f(6.0), db_to_volt_f(6.0)];
+///
+/// assert!((gain_sum_db(&stages) - 12.0).abs() < 0.01);
+/// ```
+pub fn gain_sum_db(gains: &[f32]) -> f32 {
+    let product: f64 = gains.iter().map(|&gain| gain as f64).product();
+    volt_to_db_f(product as f32)
+}
+
+/// Returns the total level in dB of chained gain stages given in dB: their sum, clamped to
+/// [-100.0, 27.0] dB. `NaN` stages are ignored.
+///
+/// The counterpart of `gain_sum_db()`: gains multiply, levels in dB add up.
+///
+/// # Example
+/// ```
+/// use audio_utils::db_sum;
+///
+/// assert_eq!(db_sum(&[6.0, 6.0]), 12.0);
+/// assert_eq!(db_sum(&[-80.0, -40.0]), -100.0);
+/// ```
+pub fn db_sum(dbs: &[f32]) -> f32 {
+    let sum: f32 = dbs.iter().filter(|db| !db.is_nan()).sum();
+    if sum.is_nan() {
+        // infinitely loud and muted stages cancel out, report the safe end
+        return DB_VOLT_LOOKUP_MIN as f32;
+    }
+    sum.clamp(DB_VOLT_LOOKUP_MIN as f32, DB_VOLT_LOOKUP_MAX as f32)
+}
+
+/// Returns the A-weighting of a single frequency in dB, per IEC 61672-1:
+/// `20 * log10(R_A(f)) + 2.00` with
+/// `R_A(f) = 12194² f⁴ / ((f² + 20.6²) * sqrt((f² + 107.7²)(f² + 737.9²)) * (f² + 12194²))`.
+///
+/// This is the weighting at one frequency, e.g. to weight the bins of a spectrum or to match
+/// the level of test tones. It is not a filter: weighting a time-domain signal requires an
+/// actual A-weighting filter.
+///
+/// # Arguments
+///
+/// * `freq_hz` - Frequency in Hz. Zero and negative frequencies yield `f32::NEG_INFINITY`,
+///   `NaN` yields 0 dB (no weighting).
+///
+/// # Example
+/// ```
+/// use audio_utils::a_weight_db;
+///
+/// assert!(a_weight_db(1000.0).abs() < 0.01);
+/// assert!((a_weight_db(100.0) + 19.1).abs() < 0.05);
+/// ```
+pub fn a_weight_db(freq_hz: f32) -> f32 {
+    if freq_hz.is_nan() {
+        return 0.0;
+    }
+    if freq_hz <= 0.0 {
+        return f32::NEG_INFINITY;
+    }
+    let f2 = freq_hz as f64 * freq_hz as f64;
+    let r_a = 12194.0_f64.powi(2) * f2 * f2
+        / ((f2 + 20.6_f64.powi(2))
+            * ((f2 + 107.7_f64.powi(2)) * (f2 + 737.9_f64.powi(2))).sqrt()
+            * (f2 + 12194.0_f64.powi(2)));
+    (20.0 * r_a.log10() + 2.0) as f32
+}
+
+/// Returns the A-weighting of a single frequency as a linear gain: `a_weight_db()` converted
+/// via `db_to_volt_f()`.
+///
+/// Weightings below -100 dB (below ~6 Hz) are clamped to the table minimum `MIN_GAIN`,
+/// zero and negative frequencies yield 0.0. See `a_weight_db()`.
+///
+/// # Example
+/// ```
+/// use audio_utils::a_weight_gain;
+///
+/// // a 100 Hz tone counts about 19 dB quieter
+/// let weighted_level = 0.5 * a_weight_gain(100.0);
+///
+/// assert!((a_weight_gain(1000.0) - 1.0).abs() < 1e-3);
+/// ```
+pub fn a_weight_gain(freq_hz: f32) -> f32 {
+    let db = a_weight_db(freq_hz);
+    if db == f32::NEG_INFINITY {
+        return 0.0;
+    }
+    db_to_volt_f(db)
+}
 
 //--- Tests ---------------------------------------------------------------------------------------
 //
@@ -452,11 +1478,80 @@ mod tests {
     fn db_to_volt_delivers_correct_values() {
         for db in -100..=27 {
             let expected = 10.0_f32.powf(db as f32 / 20.0);
-            let actual = db_to_volt(db);
-
-            // verify that the values differ by at most 0.01%
-            let ratio = expected / actual;
-            assert!(ratio >= 0.9999 && ratio <= 1.0001);
+            assert_gain_close(db_to_volt(db), expected);
+        }
+    }
+    #[test]
+    fn table_meets_published_tolerance_at_every_entry() {
+        for (i, &entry) in DB_VOLT_LOOKUP.iter().enumerate() {
+            let db = i as i32 - DB_VOLT_LOOKUP_OFFSET as i32;
+            let exact = 10.0_f64.powf(db as f64 / 20.0);
+            let rel_error = ((entry as f64 - exact) / exact).abs();
+            assert!(
+                rel_error <= CONVERSION_REL_TOLERANCE as f64,
+                "db = {db}, rel_error = {rel_error}"
+            );
+        }
+    }
+    #[test]
+    fn table_is_strictly_increasing() {
+        for pair in DB_VOLT_LOOKUP.windows(2) {
+            assert!(pair[0] < pair[1], "{} >= {}", pair[0], pair[1]);
+        }
+        assert!(DB_VOLT_LOOKUP.iter().all(|gain| gain.is_finite() && *gain > 0.0));
+    }
+    #[test]
+    fn table_entries_cover_the_supported_range() {
+        assert_eq!(table_entries().len(), supported_db_range().count());
+        for (db, gain) in table_entries() {
+            assert_eq!(gain, db_to_volt(db), "db = {db}");
+        }
+    }
+    #[test]
+    fn assert_gain_close_accepts_equal_gains() {
+        assert_gain_close(0.0, 0.0);
+        assert_gain_close(1.0, 1.00005);
+    }
+    #[test]
+    #[should_panic(expected = "Gains differ")]
+    fn assert_gain_close_rejects_distant_gains() {
+        assert_gain_close(1.0, 1.001);
+    }
+    #[test]
+    #[should_panic(expected = "Gains differ")]
+    fn assert_gain_close_rejects_nan() {
+        assert_gain_close(f32::NAN, 1.0);
+    }
+    #[test]
+    fn db_to_volt_exact_matches_powf_to_f32_precision() {
+        for step in -12_000..=3_000 {
+            let db = step as f32 / 100.0;
+            let expected = 10.0_f64.powf(db as f64 / 20.0);
+            let actual = db_to_volt_exact(db) as f64;
+            // within half an ulp of f32, i.e. correctly rounded up to f64 error
+            assert!(
+                ((actual - expected) / expected).abs() <= f32::EPSILON as f64 / 2.0,
+                "db = {db}"
+            );
+        }
+    }
+    #[test]
+    fn db_to_volt_exact_handles_non_finite_values() {
+        assert_eq!(db_to_volt_exact(f32::NEG_INFINITY), 0.0);
+        assert_eq!(db_to_volt_exact(f32::INFINITY), f32::INFINITY);
+        assert_eq!(db_to_volt_exact(f32::NAN), 1.0);
+        assert_eq!(db_to_volt_exact(0.0), 1.0);
+    }
+    #[test]
+    fn db_to_volt_checked_matches_in_range() {
+        for db in DB_VOLT_LOOKUP_MIN..=DB_VOLT_LOOKUP_MAX {
+            assert_eq!(db_to_volt_checked(db), Some(db_to_volt(db)));
+        }
+    }
+    #[test]
+    fn db_to_volt_checked_rejects_out_of_range() {
+        for db in [i32::MIN, -101, 28, i32::MAX] {
+            assert_eq!(db_to_volt_checked(db), None);
         }
     }
     #[test]
@@ -465,6 +1560,564 @@ mod tests {
         assert!(db_to_volt(28) > 20.0);
     }
 
+    /// The former hand-written table, the generated table must reproduce it up to the 3 dB
+    /// mantissa, see `db_volt_lookup()`.
+    const HAND_WRITTEN_TABLE: [f32; 128] = [
+        // -100 dB to -91 dB
+        1.0000000e-05,
+        1.1220185e-05,
+        1.2589254e-05,
+        1.4125376e-05,
+        1.5848932e-05,
+        1.7782794e-05,
+        1.9952623e-05,
+        2.2387211e-05,
+        2.5118864e-05,
+        2.8183829e-05,
+        // -90 dB to -81 dB
+        3.1622777e-05,
+        3.5481339e-05,
+        3.9810717e-05,
+        4.4668359e-05,
+        5.0118723e-05,
+        5.6234133e-05,
+        6.3095734e-05,
+        7.0794578e-05,
+        7.9432823e-05,
+        8.9125094e-05,
+        // -80 dB to -71 dB
+        1.0000000e-04,
+        1.1220185e-04,
+        1.2589254e-04,
+        1.4125376e-04,
+        1.5848932e-04,
+        1.7782794e-04,
+        1.9952623e-04,
+        2.2387211e-04,
+        2.5118864e-04,
+        2.8183829e-04,
+        // -70 dB to -61 dB
+        3.1622777e-04,
+        3.5481339e-04,
+        3.9810717e-04,
+        4.4668359e-04,
+        5.0118723e-04,
+        5.6234133e-04,
+        6.3095734e-04,
+        7.0794578e-04,
+        7.9432823e-04,
+        8.9125094e-04,
+        // -60 dB to -51 dB
+        1.0000000e-03,
+        1.1220185e-03,
+        1.2589254e-03,
+        1.4125376e-03,
+        1.5848932e-03,
+        1.7782794e-03,
+        1.9952623e-03,
+        2.2387211e-03,
+        2.5118864e-03,
+        2.8183829e-03,
+        // -50 dB to -41 dB
+        3.1622777e-03,
+        3.5481339e-03,
+        3.9810717e-03,
+        4.4668359e-03,
+        5.0118723e-03,
+        5.6234133e-03,
+        6.3095734e-03,
+        7.0794578e-03,
+        7.9432823e-03,
+        8.9125094e-03,
+        // -40 dB to -31 dB
+        1.0000000e-02,
+        1.1220185e-02,
+        1.2589254e-02,
+        1.4125376e-02,
+        1.5848932e-02,
+        1.7782794e-02,
+        1.9952623e-02,
+        2.2387211e-02,
+        2.5118864e-02,
+        2.8183829e-02,
+        // -30 dB to -21 dB
+        3.1622777e-02,
+        3.5481339e-02,
+        3.9810717e-02,
+        4.4668359e-02,
+        5.0118723e-02,
+        5.6234133e-02,
+        6.3095734e-02,
+        7.0794578e-02,
+        7.9432823e-02,
+        8.9125094e-02,
+        // -20 dB to -11 dB
+        1.0000000e-01,
+        1.1220185e-01,
+        1.2589254e-01,
+        1.4125376e-01,
+        1.5848932e-01,
+        1.7782794e-01,
+        1.9952623e-01,
+        2.2387211e-01,
+        2.5118864e-01,
+        2.8183829e-01,
+        // -10 dB to -1 dB
+        3.1622777e-01,
+        3.5481339e-01,
+        3.9810717e-01,
+        4.4668359e-01,
+        5.0118723e-01,
+        5.6234133e-01,
+        6.3095734e-01,
+        7.0794578e-01,
+        7.9432823e-01,
+        8.9125094e-01,
+        // 0 dB to +9 dB
+        1.0000000e+00,
+        1.1220185e+00,
+        1.2589254e+00,
+        1.4125376e+00,
+        1.5848932e+00,
+        1.7782794e+00,
+        1.9952623e+00,
+        2.2387211e+00,
+        2.5118864e+00,
+        2.8183829e+00,
+        // +10 dB to +19 dB
+        3.1622777e+00,
+        3.5481339e+00,
+        3.9810717e+00,
+        4.4668359e+00,
+        5.0118723e+00,
+        5.6234133e+00,
+        6.3095734e+00,
+        7.0794578e+00,
+        7.9432823e+00,
+        8.9125094e+00,
+        // +20 dB to +27 dB
+        1.0000000e+01,
+        1.1220185e+01,
+        1.2589254e+01,
+        1.4125376e+01,
+        1.5848932e+01,
+        1.7782794e+01,
+        1.9952623e+01,
+        2.2387211e+01,
+    ];
+
+    #[test]
+    fn generated_table_matches_hand_written_table() {
+        assert_eq!(DB_VOLT_LOOKUP_SIZE, HAND_WRITTEN_TABLE.len());
+        for (idx, (&generated, &written)) in DB_VOLT_LOOKUP.iter().zip(&HAND_WRITTEN_TABLE).enumerate() {
+            let db = idx as i32 + DB_VOLT_LOOKUP_MIN;
+            // the hand-written table rounded 10^(3/20) = 1.41253754... up to 1.4125376
+            let max_ulps = if db.rem_euclid(20) == 3 { 1 } else { 0 };
+            assert!(
+                generated.to_bits().abs_diff(written.to_bits()) <= max_ulps,
+                "{} dB: generated {:e}, hand-written {:e}",
+                db,
+                generated,
+                written
+            );
+        }
+    }
+    #[test]
+    fn range_constants_match_the_clamping() {
+        assert_eq!(supported_db_range(), -100..=27);
+        assert_eq!(db_to_volt(MIN_DB), MIN_GAIN);
+        assert_eq!(db_to_volt(MAX_DB), MAX_GAIN);
+        assert_eq!(db_to_volt(MIN_DB - 1), MIN_GAIN);
+        assert_eq!(db_to_volt(MAX_DB + 1), MAX_GAIN);
+        assert_eq!(volt_to_db(MIN_GAIN), MIN_DB);
+        assert_eq!(volt_to_db(MAX_GAIN), MAX_DB);
+    }
+    #[test]
+    fn db_to_volt_is_usable_in_const_context() {
+        const HALF_POWER: f32 = db_to_volt(-3);
+        const CLAMPED_LOW: f32 = db_to_volt(-1000);
+        const CLAMPED_HIGH: f32 = db_to_volt(1000);
+
+        assert_eq!(HALF_POWER, db_to_volt(-3));
+        assert_eq!(CLAMPED_LOW, db_to_volt(DB_VOLT_LOOKUP_MIN));
+        assert_eq!(CLAMPED_HIGH, db_to_volt(DB_VOLT_LOOKUP_MAX));
+    }
+
+    //--- db_to_volt_slice
+    #[test]
+    fn db_to_volt_slice_matches_scalar_conversion() {
+        use rand::{Rng, SeedableRng, rngs::SmallRng};
+
+        let mut rng = SmallRng::seed_from_u64(0xDEC1_BA5E_u64);
+        // odd length to exercise the remainder, range beyond the table to exercise clamping
+        let dbs: Vec<i32> = (0..10_007).map(|_| rng.random_range(-200..=200)).collect();
+        let mut volts = vec![0.0f32; dbs.len()];
+
+        db_to_volt_slice(&dbs, &mut volts);
+
+        for (&db, &volt) in dbs.iter().zip(&volts) {
+            assert_eq!(volt.to_bits(), db_to_volt(db).to_bits(), "db = {db}");
+        }
+    }
+    #[test]
+    #[should_panic(expected = "Input and output slices must have the same length")]
+    fn db_to_volt_slice_panics_on_length_mismatch() {
+        db_to_volt_slice(&[0, 1], &mut [0.0]);
+    }
+
+    //--- volt_to_db_slice
+    #[test]
+    fn volt_to_db_slices_match_scalar_conversion() {
+        use rand::{Rng, SeedableRng, rngs::SmallRng};
+
+        let mut rng = SmallRng::seed_from_u64(0x5EC7_0DB5_u64);
+        // levels from below the table to above it, plus the special values
+        let mut gains: Vec<f32> =
+            (0..10_007).map(|_| 10.0f32.powf(rng.random_range(-6.0..2.0))).collect();
+        gains.extend([0.0, -0.5, f32::NAN, f32::INFINITY]);
+        let mut dbs = vec![0i32; gains.len()];
+        let mut dbs_f = vec![0.0f32; gains.len()];
+
+        volt_to_db_slice(&gains, &mut dbs);
+        volt_to_db_slice_f(&gains, &mut dbs_f);
+
+        for ((&gain, &db), &db_f) in gains.iter().zip(&dbs).zip(&dbs_f) {
+            assert_eq!(db, volt_to_db(gain), "gain = {gain}");
+            assert_eq!(db_f.to_bits(), volt_to_db_f(gain).to_bits(), "gain = {gain}");
+        }
+    }
+    #[test]
+    #[should_panic(expected = "Input and output slices must have the same length")]
+    fn volt_to_db_slice_panics_on_length_mismatch() {
+        volt_to_db_slice(&[0.5], &mut [0, 0]);
+    }
+    #[test]
+    #[should_panic(expected = "Input and output slices must have the same length")]
+    fn volt_to_db_slice_f_panics_on_length_mismatch() {
+        volt_to_db_slice_f(&[0.5, 0.25], &mut [0.0]);
+    }
+
+    //--- db_to_volt_f
+    #[test]
+    fn db_to_volt_f_matches_table_at_integer_values() {
+        for db in DB_VOLT_LOOKUP_MIN..=DB_VOLT_LOOKUP_MAX {
+            assert_eq!(db_to_volt_f(db as f32), db_to_volt(db));
+        }
+    }
+    #[test]
+    fn db_to_volt_f_interpolates_between_table_values() {
+        let mut db = -100.0f32;
+        while db <= 27.0 {
+            let expected = 10.0_f32.powf(db / 20.0);
+            let actual = db_to_volt_f(db);
+
+            // linear interpolation between geometric table entries: at most ~0.3% error
+            let ratio = expected / actual;
+            assert!(ratio >= 0.997 && ratio <= 1.003, "db = {db}");
+            db += 0.1;
+        }
+    }
+    #[test]
+    fn db_to_volt_f_clamps_values() {
+        assert_eq!(db_to_volt_f(-120.5), db_to_volt(-100));
+        assert_eq!(db_to_volt_f(30.5), db_to_volt(27));
+    }
+    #[test]
+    fn db_to_volt_f_handles_non_finite_values() {
+        assert_eq!(db_to_volt_f(f32::NAN), 1.0);
+        assert_eq!(db_to_volt_f(f32::INFINITY), 1.0);
+        assert_eq!(db_to_volt_f(f32::NEG_INFINITY), 1.0);
+    }
+
+    //--- velocity_to_gain
+    #[test]
+    fn velocity_to_gain_maps_endpoints() {
+        assert_eq!(velocity_to_gain(0, -60, 0), db_to_volt(-60));
+        assert_eq!(velocity_to_gain(127, -60, 0), db_to_volt(0));
+    }
+    #[test]
+    fn velocity_to_gain_maps_midpoint_linear_in_db() {
+        // 64/127 of 60 dB is 30.2 dB above the minimum
+        assert_eq!(velocity_to_gain(64, -60, 0), db_to_volt(-30));
+        assert_eq!(velocity_to_gain(64, -40, 20), db_to_volt(-10));
+    }
+    #[test]
+    fn velocity_to_gain_handles_extreme_bounds() {
+        assert_eq!(velocity_to_gain(0, -100, i32::MAX), MIN_GAIN);
+        assert_eq!(velocity_to_gain(64, -100, i32::MAX), MAX_GAIN);
+        assert_eq!(velocity_to_gain(127, -100, i32::MAX), MAX_GAIN);
+
+        assert_eq!(velocity_to_gain(0, i32::MIN, -100), MIN_GAIN);
+        assert_eq!(velocity_to_gain(127, i32::MIN, -100), MIN_GAIN);
+        assert_eq!(velocity_to_gain(127, i32::MIN, 0), 1.0);
+
+        assert_eq!(velocity_to_gain(0, i32::MIN, i32::MAX), MIN_GAIN);
+        assert_eq!(velocity_to_gain(127, i32::MIN, i32::MAX), MAX_GAIN);
+        // inverted bounds map downwards instead of overflowing
+        assert_eq!(velocity_to_gain(127, i32::MAX, i32::MIN), MIN_GAIN);
+    }
+    #[test]
+    fn velocity_to_gain_clamps_velocity() {
+        assert_eq!(velocity_to_gain(200, -60, 0), db_to_volt(0));
+        assert_eq!(velocity_to_gain(u8::MAX, -60, 0), db_to_volt(0));
+    }
+    #[test]
+    fn cc_to_gain_maps_endpoints_and_rises_monotonically() {
+        assert_eq!(cc_to_gain(0), 0.0);
+        assert_eq!(cc_to_gain(1), db_to_volt(-60));
+        assert_eq!(cc_to_gain(64), db_to_volt(-30));
+        assert_eq!(cc_to_gain(127), 1.0);
+        assert_eq!(cc_to_gain(200), 1.0);
+        for cc in 1..=127u8 {
+            assert!(cc_to_gain(cc) > cc_to_gain(cc - 1), "cc = {cc}");
+        }
+    }
+
+    //--- fader_to_db
+    const FADER_LAWS: [FaderLaw; 3] = [
+        FaderLaw::Linear,
+        FaderLaw::AudioTaper,
+        FaderLaw::Knee {
+            position: 0.25,
+            db: -40.0,
+        },
+    ];
+
+    #[test]
+    fn fader_to_db_maps_endpoints_for_all_laws() {
+        for law in FADER_LAWS {
+            assert_eq!(fader_to_db(1.0, law), 0.0, "{law:?}");
+            assert_eq!(fader_to_db(2.0, law), 0.0, "{law:?}");
+            assert_eq!(fader_to_db(0.0, law), f32::NEG_INFINITY, "{law:?}");
+            assert_eq!(fader_to_db(-1.0, law), f32::NEG_INFINITY, "{law:?}");
+            assert_eq!(fader_to_db(f32::NAN, law), f32::NEG_INFINITY, "{law:?}");
+            assert_eq!(fader_to_gain(0.0, law), 0.0, "{law:?}");
+            assert!((fader_to_gain(1.0, law) - 1.0).abs() < 1e-6, "{law:?}");
+        }
+    }
+
+    #[test]
+    fn fader_to_db_rises_monotonically_for_all_laws() {
+        for law in FADER_LAWS {
+            let mut last = f32::NEG_INFINITY;
+            for i in 1..=1000 {
+                let db = fader_to_db(i as f32 / 1000.0, law);
+                assert!(db > last, "{law:?} at {i}");
+                last = db;
+            }
+        }
+    }
+
+    #[test]
+    fn audio_taper_spends_more_range_in_the_lower_half() {
+        let range = |law, from, to| fader_to_db(to, law) - fader_to_db(from, law);
+        let linear_upper = range(FaderLaw::Linear, 0.5, 1.0);
+        let linear_lower = range(FaderLaw::Linear, 0.01, 0.5);
+        let taper_upper = range(FaderLaw::AudioTaper, 0.5, 1.0);
+        let taper_lower = range(FaderLaw::AudioTaper, 0.01, 0.5);
+        assert!(taper_lower > linear_lower);
+        assert!(taper_lower > taper_upper);
+        // finer resolution near 0 dB
+        assert!(taper_upper < linear_upper);
+    }
+
+    #[test]
+    fn knee_law_is_continuous_at_the_knee() {
+        let law = FaderLaw::Knee {
+            position: 0.25,
+            db: -40.0,
+        };
+        assert_eq!(fader_to_db(0.25, law), -40.0);
+        assert!((fader_to_db(0.25 - 1e-6, law) + 40.0).abs() < 1e-3);
+        // the gain falls linearly below the knee: half the position is -6 dB
+        assert!((fader_to_db(0.125, law) + 46.02).abs() < 0.01);
+    }
+
+    #[test]
+    fn knee_law_handles_invalid_knees() {
+        for (position, db) in [(f32::NAN, -40.0), (0.5, f32::INFINITY), (-1.0, -40.0)] {
+            let law = FaderLaw::Knee { position, db };
+            let db = fader_to_db(0.5, law);
+            assert!(db.is_finite() && db <= 0.0, "{position} {db}");
+            assert_eq!(fader_to_db(1.0, law), 0.0);
+        }
+        // a knee at 1.0 leaves only the linear-gain part
+        let law = FaderLaw::Knee {
+            position: 1.0,
+            db: 0.0,
+        };
+        assert!((fader_to_db(0.5, law) + 6.02).abs() < 0.01);
+    }
+
+    //--- volt_to_db_f
+    #[test]
+    fn volt_to_db_f_matches_table_at_integer_values() {
+        for db in DB_VOLT_LOOKUP_MIN..=DB_VOLT_LOOKUP_MAX {
+            assert_eq!(volt_to_db_f(db_to_volt(db)), db as f32);
+        }
+    }
+    #[test]
+    fn db_to_volt_f_and_volt_to_db_f_are_inverse_functions() {
+        let mut db = -100.0f32;
+        while db <= 27.0 {
+            let actual = volt_to_db_f(db_to_volt_f(db));
+            assert!((actual - db).abs() < 1e-3, "db = {db}, actual = {actual}");
+            db += 0.1;
+        }
+    }
+    #[test]
+    fn volt_to_db_f_clamps_values() {
+        assert_eq!(volt_to_db_f(f32::MIN_POSITIVE), -100.0);
+        assert_eq!(volt_to_db_f(f32::NAN), -100.0);
+        assert_eq!(volt_to_db_f(f32::MAX), 27.0);
+        assert_eq!(volt_to_db_f(-0.5), volt_to_db_f(0.5));
+    }
+
+    #[test]
+    fn volt_to_db_f_distinguishes_silence_from_quiet() {
+        assert_eq!(volt_to_db_f(0.0), f32::NEG_INFINITY);
+        assert_eq!(volt_to_db_f(-0.0), f32::NEG_INFINITY);
+        assert_eq!(volt_to_db_f(1e-6), -100.0);
+        // the integer conversion is unchanged
+        assert_eq!(volt_to_db(0.0), -100);
+        assert_eq!(volt_to_db(1e-6), -100);
+    }
+
+    //--- DbConvention
+    #[test]
+    fn db_to_volt_with_uses_the_convention() {
+        let amplitude = db_to_volt_with(-3.0, DbConvention::Amplitude);
+        let power = db_to_volt_with(-3.0, DbConvention::Power);
+        assert!((amplitude - 0.7079).abs() < 1e-3);
+        assert!((power - 0.5012).abs() < 1e-3);
+    }
+    #[test]
+    fn volt_to_db_with_uses_the_convention() {
+        assert!((volt_to_db_with(0.5, DbConvention::Power) + 3.01).abs() < 0.01);
+        assert!((volt_to_db_with(0.5, DbConvention::Amplitude) + 6.02).abs() < 0.01);
+    }
+    #[test]
+    fn power_convention_round_trips() {
+        for db in [-50.0f32, -20.5, -3.0, 0.0, 6.0, 13.5] {
+            let power = db_to_volt_with(db, DbConvention::Power);
+            let actual = volt_to_db_with(power, DbConvention::Power);
+            assert!((actual - db).abs() < 1e-3, "db = {db}, actual = {actual}");
+        }
+    }
+    #[test]
+    fn amplitude_is_the_default_convention() {
+        assert_eq!(DbConvention::default(), DbConvention::Amplitude);
+        assert_eq!(db_to_volt_with(-6.5, DbConvention::default()), db_to_volt_f(-6.5));
+    }
+
+    //--- dBFS
+    #[test]
+    fn gain_to_dbfs_is_referenced_to_full_scale() {
+        assert_eq!(gain_to_dbfs(1.0), 0.0);
+        assert!((gain_to_dbfs(0.5) + 6.02).abs() < 0.01);
+        assert_eq!(gain_to_dbfs(0.0), f32::NEG_INFINITY);
+    }
+    #[test]
+    fn headroom_db_is_distance_to_full_scale() {
+        assert!((headroom_db(0.5) - 6.02).abs() < 0.01);
+        assert_eq!(headroom_db(1.0), 0.0);
+        assert_eq!(headroom_db(-1.0), 0.0);
+        assert!((headroom_db(2.0) + 6.02).abs() < 0.01);
+        assert_eq!(headroom_db(1e9), -27.0);
+        assert_eq!(headroom_db(1e-9), 100.0);
+        assert_eq!(headroom_db(0.0), f32::INFINITY);
+    }
+    #[test]
+    fn db_difference_reports_relative_level() {
+        assert!((db_difference(1.0, 0.5) - 6.0206).abs() < 1e-4);
+        assert!((db_difference(0.5, 1.0) + 6.0206).abs() < 1e-4);
+        assert!((db_difference(-0.5, 0.05) - 20.0).abs() < 1e-4);
+        // beyond the table range
+        assert!((db_difference(1.0, 1e-8) - 160.0).abs() < 1e-3);
+    }
+    #[test]
+    fn db_difference_handles_silence_and_non_finite_gains() {
+        assert_eq!(db_difference(0.5, 0.0), f32::INFINITY);
+        assert_eq!(db_difference(0.0, 0.5), f32::NEG_INFINITY);
+        assert_eq!(db_difference(0.0, 0.0), 0.0);
+        assert_eq!(db_difference(f32::NAN, 0.5), 0.0);
+        assert_eq!(db_difference(f32::INFINITY, f32::INFINITY), 0.0);
+        assert_eq!(db_difference(f32::INFINITY, 0.5), f32::INFINITY);
+    }
+    #[test]
+    fn dbfs_to_gain_is_referenced_to_full_scale() {
+        assert_eq!(dbfs_to_gain(0.0), 1.0);
+        assert!((dbfs_to_gain(-6.02) - 0.5).abs() < 1e-3);
+    }
+    #[test]
+    fn sample_to_dbfs_ignores_the_sign() {
+        assert_eq!(sample_to_dbfs(0.5), sample_to_dbfs(-0.5));
+        assert_eq!(sample_to_dbfs(-1.0), 0.0);
+    }
+
+    //--- peak_normalize_gain
+    #[test]
+    fn peak_normalize_gain_brings_the_peak_to_the_target() {
+        use rand::{Rng, SeedableRng, rngs::SmallRng};
+
+        let mut rng = SmallRng::seed_from_u64(0x9EA4_0DB5_u64);
+        for _ in 0..1000 {
+            // within the 27 dB of gain the table allows
+            let scale = rng.random_range(0.1..4.0);
+            let target_dbfs = rng.random_range(-40.0..0.0);
+            let buffer: Vec<f32> = (0..64).map(|_| rng.random_range(-scale..scale)).collect();
+
+            let gain = peak_normalize_gain(&buffer, target_dbfs);
+            let new_peak = buffer.iter().fold(0.0f32, |peak, s| peak.max((s * gain).abs()));
+            let new_peak_db = 20.0 * new_peak.log10();
+            assert!(
+                (new_peak_db - target_dbfs).abs() < 0.05,
+                "scale {scale}, target {target_dbfs}: {new_peak_db}"
+            );
+        }
+    }
+
+    #[test]
+    fn peak_normalize_gain_handles_silence_and_invalid_targets() {
+        assert_eq!(peak_normalize_gain(&[], -1.0), 1.0);
+        assert_eq!(peak_normalize_gain(&[0.0; 8], -1.0), 1.0);
+        assert_eq!(peak_normalize_gain(&[1e-6, -1e-6], -1.0), 1.0);
+        assert_eq!(peak_normalize_gain(&[0.5], f32::NAN), 1.0);
+        assert_eq!(peak_normalize_gain(&[0.5], f32::INFINITY), 1.0);
+        // limited to the table range
+        assert_eq!(peak_normalize_gain(&[1e-3], 0.0), MAX_GAIN);
+    }
+
+    //--- gain_add_db
+    #[test]
+    fn gain_add_db_multiplies_without_rounding() {
+        assert!((gain_add_db(0.5, 6.0) - 1.0).abs() < 0.01);
+        // 0.37 is not a table value and stays off the table grid
+        assert_eq!(gain_add_db(0.37, 0.0), 0.37);
+        assert_eq!(gain_add_db(0.37, -20.0), 0.37 * 0.1);
+        assert_eq!(gain_add_db(0.37, f32::NAN), 0.37);
+    }
+
+    //--- gain_sum_db / db_sum
+    #[test]
+    fn gain_sum_db_combines_stages() {
+        let stages = [db_to_volt(6), db_to_volt(6)];
+        assert!((gain_sum_db(&stages) - 12.0).abs() < 0.01);
+        assert!((gain_sum_db(&[db_to_volt(-20), db_to_volt(6), 2.0]) + 7.98).abs() < 0.01);
+        assert_eq!(gain_sum_db(&[]), 0.0);
+        assert_eq!(gain_sum_db(&[0.5, 0.0]), f32::NEG_INFINITY);
+        assert_eq!(gain_sum_db(&[20.0, 20.0]), 27.0);
+    }
+    #[test]
+    fn db_sum_adds_and_clamps() {
+        assert_eq!(db_sum(&[6.0, 6.0]), 12.0);
+        assert_eq!(db_sum(&[]), 0.0);
+        assert_eq!(db_sum(&[-3.0, f32::NAN, 1.5]), -1.5);
+        assert_eq!(db_sum(&[20.0, 20.0]), 27.0);
+        assert_eq!(db_sum(&[0.0, f32::NEG_INFINITY]), -100.0);
+        assert_eq!(db_sum(&[f32::INFINITY, f32::NEG_INFINITY]), -100.0);
+    }
+
     //--- Edge case tests for DbTovolt trait
     #[test]
     fn db_to_volt_handles_nan_f32() {
@@ -543,6 +2196,49 @@ mod tests {
         assert_eq!(result, -100); // Should return minimum dB
     }
 
+    //--- PCM samples
+    #[test]
+    fn pcm_full_scale_is_0_dbfs() {
+        assert_eq!(i16::MAX.to_db(), 0);
+        assert_eq!(i32::MAX.to_db(), 0);
+        assert_eq!(pcm_to_dbfs(i16::MAX), 0.0);
+        assert_eq!(pcm_to_dbfs(i32::MAX), 0.0);
+        // the negative full scale is one step louder, still 0 dBFS when rounded
+        assert_eq!(i16::MIN.to_db(), 0);
+        assert!(pcm_to_dbfs(i16::MIN) < 0.01);
+    }
+    #[test]
+    fn pcm_half_scale_is_minus_6_dbfs() {
+        assert_eq!((-16384i16).to_db(), -6);
+        assert_eq!((i32::MAX / 2).to_db(), -6);
+        assert!((pcm_to_dbfs(16384i16) + 6.02).abs() < 0.01);
+        assert!((pcm_to_dbfs(i32::MIN / 2) + 6.02).abs() < 0.01);
+    }
+    #[test]
+    fn pcm_zero_is_silence() {
+        assert_eq!(0i16.to_db(), -100);
+        assert_eq!(0i32.to_db(), -100);
+        assert_eq!(pcm_to_dbfs(0i16), f32::NEG_INFINITY);
+        assert_eq!(pcm_to_dbfs(0i32), f32::NEG_INFINITY);
+    }
+
+    //--- volt_to_db_any
+    #[test]
+    fn volt_to_db_any_accepts_floats_without_cast() {
+        for db in DB_VOLT_LOOKUP_MIN..=DB_VOLT_LOOKUP_MAX {
+            let gain = db_to_volt(db);
+            assert_eq!(volt_to_db_any(gain), db);
+            assert_eq!(volt_to_db_any(gain as f64), db);
+        }
+    }
+    #[test]
+    fn volt_to_db_any_maps_pcm_full_scale_to_0_dbfs() {
+        assert_eq!(volt_to_db_any(i16::MAX), 0);
+        assert_eq!(volt_to_db_any(i16::MIN), 0);
+        assert_eq!(volt_to_db_any(i32::MAX), 0);
+        assert_eq!(volt_to_db_any(i32::MIN), 0);
+    }
+
 
     #[test]
     fn db_to_volt_is_performant() {
@@ -609,6 +2305,210 @@ mod tests {
         assert_eq!(volt_to_db(voltage_below), 0);
     }
 
+    #[test]
+    fn volt_to_db_rounds_at_geometric_mean() {
+        for db in DB_VOLT_LOOKUP_MIN..DB_VOLT_LOOKUP_MAX {
+            // the rounding boundary between db and db + 1 is at db + 0.5 in the log domain
+            let below = 10.0_f64.powf((db as f64 + 0.49) / 20.0) as f32;
+            let above = 10.0_f64.powf((db as f64 + 0.51) / 20.0) as f32;
+            assert_eq!(volt_to_db(below), db);
+            assert_eq!(volt_to_db(above), db + 1);
+        }
+    }
+    #[test]
+    fn volt_to_db_is_not_biased_toward_lower_db() {
+        // -6.49 dB lies between the geometric mean (-6.5 dB) and the linear midpoint of the
+        // -7 dB and -6 dB entries: it is nearer to -6 dB in the log domain.
+        let lower = db_to_volt(-7);
+        let upper = db_to_volt(-6);
+        let gain = 10.0_f32.powf(-6.49 / 20.0);
+        assert!(gain < (lower + upper) / 2.0);
+        assert_eq!(volt_to_db(gain), -6);
+    }
+
+    //--- volt_to_db_floor / volt_to_db_ceil
+    #[test]
+    fn volt_to_db_floor_and_ceil_bracket_the_nearest_value() {
+        for db in DB_VOLT_LOOKUP_MIN..DB_VOLT_LOOKUP_MAX {
+            let geometric_mean = (db_to_volt(db) as f64 * db_to_volt(db + 1) as f64).sqrt() as f32;
+            let floor = volt_to_db_floor(geometric_mean);
+            let ceil = volt_to_db_ceil(geometric_mean);
+            assert_eq!((floor, ceil), (db, db + 1));
+            assert!((floor..=ceil).contains(&volt_to_db(geometric_mean)));
+        }
+    }
+    #[test]
+    fn volt_to_db_floor_and_ceil_are_exact_on_table_values() {
+        for db in DB_VOLT_LOOKUP_MIN..=DB_VOLT_LOOKUP_MAX {
+            assert_eq!(volt_to_db_floor(db_to_volt(db)), db);
+            assert_eq!(volt_to_db_ceil(db_to_volt(db)), db);
+        }
+    }
+    #[test]
+    fn volt_to_db_floor_and_ceil_clamp() {
+        for gain in [0.0, 1e-9, f32::NAN] {
+            assert_eq!(volt_to_db_floor(gain), DB_VOLT_LOOKUP_MIN);
+            assert_eq!(volt_to_db_ceil(gain), DB_VOLT_LOOKUP_MIN);
+        }
+        for gain in [100.0, f32::INFINITY] {
+            assert_eq!(volt_to_db_floor(gain), DB_VOLT_LOOKUP_MAX);
+            assert_eq!(volt_to_db_ceil(gain), DB_VOLT_LOOKUP_MAX);
+        }
+    }
+
+    //--- A-weighting
+    #[test]
+    fn a_weight_db_matches_standard_values() {
+        // IEC 61672-1 table values, rounded to 0.1 dB and given at the nominal frequencies
+        // (e.g. 31.5 Hz for 31.62 Hz), hence the tolerance
+        let table = [
+            (31.5, -39.4),
+            (63.0, -26.2),
+            (100.0, -19.1),
+            (250.0, -8.6),
+            (500.0, -3.2),
+            (1000.0, 0.0),
+            (2000.0, 1.2),
+            (4000.0, 1.0),
+            (8000.0, -1.1),
+            (16000.0, -6.6),
+        ];
+        for (freq, expected) in table {
+            let db = a_weight_db(freq);
+            assert!((db - expected).abs() < 0.15, "{freq} Hz: {db} dB");
+        }
+    }
+    #[test]
+    fn a_weight_handles_edge_cases() {
+        assert_eq!(a_weight_db(0.0), f32::NEG_INFINITY);
+        assert_eq!(a_weight_db(-100.0), f32::NEG_INFINITY);
+        assert_eq!(a_weight_db(f32::NAN), 0.0);
+        assert_eq!(a_weight_gain(0.0), 0.0);
+        assert_eq!(a_weight_gain(1.0), MIN_GAIN);
+        assert_eq!(a_weight_gain(f32::NAN), 1.0);
+        assert!((a_weight_gain(100.0) - db_to_volt_f(-19.14)).abs() < 1e-3);
+    }
+
+    //--- gain_to_db
+    #[test]
+    fn gain_to_db_dispatches_on_output_type() {
+        for gain in [0.0, 1e-6, 0.001, 0.49, 1.0, 3.0, 100.0, -0.5, f32::NAN, f32::INFINITY] {
+            assert_eq!(gain_to_db::<i32>(gain), gain.to_db(), "gain = {gain}");
+            let db: f32 = gain_to_db(gain);
+            assert_eq!(db.to_bits(), volt_to_db_f(gain).to_bits(), "gain = {gain}");
+        }
+        assert_eq!(gain_to_db::<f32>(0.0), f32::NEG_INFINITY);
+        assert_eq!(gain_to_db::<i32>(0.0), -100);
+    }
+
+    //--- snap_gain
+    #[test]
+    fn snap_gain_matches_volt_to_db_and_table() {
+        use rand::{Rng, SeedableRng, rngs::SmallRng};
+
+        let mut rng = SmallRng::seed_from_u64(0x5A9_6A1);
+        let gains = (0..10_000)
+            .map(|_| rng.random_range(-30.0..30.0f32))
+            .chain([0.0, 1e-9, 1.0, 0.49, f32::NAN, f32::INFINITY, f32::NEG_INFINITY]);
+        for gain in gains {
+            let (db, snapped) = snap_gain(gain);
+            assert_eq!(db, volt_to_db(gain), "gain = {gain}");
+            assert_eq!(snapped.to_bits(), db_to_volt(db).to_bits(), "gain = {gain}");
+        }
+    }
+
+    //--- db_boundary_gain
+    #[test]
+    fn db_boundary_gain_is_where_volt_to_db_flips() {
+        for db in DB_VOLT_LOOKUP_MIN..DB_VOLT_LOOKUP_MAX {
+            let boundary = db_boundary_gain(db);
+            assert_eq!(volt_to_db(boundary.next_down()), db);
+            assert_eq!(volt_to_db(boundary), db + 1);
+
+            // close to the exact geometric mean
+            let exact = 10.0_f64.powf((db as f64 + 0.5) / 20.0);
+            assert!((boundary as f64 / exact - 1.0).abs() < 1e-4, "db = {db}");
+        }
+    }
+    #[test]
+    fn db_boundary_gain_clamps() {
+        assert_eq!(db_boundary_gain(-200), db_boundary_gain(DB_VOLT_LOOKUP_MIN));
+        assert_eq!(db_boundary_gain(100), db_boundary_gain(DB_VOLT_LOOKUP_MAX - 1));
+    }
+
+    //--- volt_to_db properties: bracketing and monotonicity
+    /// Checks that every gain between two adjacent table entries maps to one of the two
+    /// bracketing dB values and that the mapping is monotonic. `step` is the distance in f32
+    /// bit patterns between checked gains; 1 checks every representable f32.
+    fn check_volt_to_db_bracketing(step: usize) {
+        let mut last_db = DB_VOLT_LOOKUP_MIN;
+        for idx in 0..DB_VOLT_LOOKUP_SIZE - 1 {
+            let lower = DB_VOLT_LOOKUP[idx];
+            let upper = DB_VOLT_LOOKUP[idx + 1];
+            let lower_db = idx as i32 + DB_VOLT_LOOKUP_MIN;
+            let boundary = (lower * upper).sqrt();
+
+            // the entries, their neighbours and the neighbourhood of the rounding boundary
+            let mut gains = vec![
+                lower,
+                lower.next_up(),
+                boundary.next_down().next_down(),
+                boundary.next_down(),
+                boundary,
+                boundary.next_up(),
+                boundary.next_up().next_up(),
+                upper.next_down(),
+                upper,
+            ];
+            gains.extend(
+                (lower.to_bits()..=upper.to_bits())
+                    .step_by(step)
+                    .map(f32::from_bits),
+            );
+            gains.sort_by(f32::total_cmp);
+
+            for gain in gains {
+                let db = volt_to_db(gain);
+                assert!(
+                    db == lower_db || db == lower_db + 1,
+                    "gain {gain:e} between {lower:e} and {upper:e} maps to {db} dB"
+                );
+                assert!(db >= last_db, "not monotonic at gain {gain:e}");
+                last_db = db;
+            }
+        }
+    }
+
+    #[test]
+    fn volt_to_db_stays_within_bracketing_entries() {
+        check_volt_to_db_bracketing(997);
+    }
+
+    #[test]
+    #[ignore = "Exhaustive over all f32 in the table range - run with cargo test -- --ignored"]
+    fn volt_to_db_stays_within_bracketing_entries_exhaustive() {
+        check_volt_to_db_bracketing(1);
+    }
+
+    #[test]
+    fn volt_to_db_handles_values_just_inside_the_clamping_shortcuts() {
+        let smallest = DB_VOLT_LOOKUP[0];
+        let largest = DB_VOLT_LOOKUP[DB_VOLT_LOOKUP_SIZE - 1];
+        assert_eq!(volt_to_db(smallest.next_down()), DB_VOLT_LOOKUP_MIN);
+        assert_eq!(volt_to_db(smallest), DB_VOLT_LOOKUP_MIN);
+        assert_eq!(volt_to_db(smallest.next_up()), DB_VOLT_LOOKUP_MIN);
+        assert_eq!(volt_to_db(largest.next_down()), DB_VOLT_LOOKUP_MAX);
+        assert_eq!(volt_to_db(largest), DB_VOLT_LOOKUP_MAX);
+        assert_eq!(volt_to_db(largest.next_up()), DB_VOLT_LOOKUP_MAX);
+    }
+
+    #[test]
+    fn volt_to_db_maps_every_table_entry_exactly() {
+        for (idx, &gain) in DB_VOLT_LOOKUP.iter().enumerate() {
+            assert_eq!(volt_to_db(gain), idx as i32 + DB_VOLT_LOOKUP_MIN);
+        }
+    }
+
     #[test]
     #[ignore = "Performance benchmark - run with cargo test -- --ignored"]
     fn volt_to_db_is_performant() {
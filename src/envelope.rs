@@ -0,0 +1,266 @@
+//! An ADSR envelope built from one-pole segments.
+//!
+//! Each segment is an exponential curve like that of an analog envelope generator, produced
+//! by a `TinySmoother` whose coefficient changes with the stage. The attack aims at twice
+//! the peak level, so the curve is still rising steeply when it reaches the peak exactly
+//! after the attack time. Decay and release approach their level asymptotically and snap
+//! to it once closer than -100 dB.
+//!
+//! # Examples
+//!
+//! ```
+//! use audio_utils::{Adsr, AdsrStage};
+//!
+//! // 5 ms attack, 50 ms decay to 0.6, 200 ms release at 48 kHz
+//! let mut envelope = Adsr::new(48_000.0, 5.0, 50.0, 0.6, 200.0);
+//!
+//! envelope.note_on();
+//! let gain = envelope.next();
+//! assert_eq!(envelope.stage(), AdsrStage::Attack);
+//! ```
+
+use crate::decibels::MIN_GAIN;
+use crate::tiny_smoother::{beta_from_half_life, ms_to_samples, TinySmoother};
+
+/// Target of the attack segment: the half-life of the attack curve is the attack time, so
+/// it crosses the peak level (1.0) exactly after the attack time.
+const ATTACK_TARGET: f32 = 2.0;
+
+/// The stage an `Adsr` is in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdsrStage {
+    /// No note, the output is 0.0.
+    Idle,
+    /// Rising toward the peak level 1.0 after `note_on()`.
+    Attack,
+    /// Falling from the peak toward the sustain level.
+    Decay,
+    /// Holding the sustain level until `note_off()`.
+    Sustain,
+    /// Falling toward 0.0 after `note_off()`.
+    Release,
+}
+
+/// An attack-decay-sustain-release envelope with exponential segments.
+///
+/// # Example
+/// ```
+/// use audio_utils::{Adsr, AdsrStage};
+///
+/// let mut envelope = Adsr::new(48_000.0, 1.0, 10.0, 0.5, 10.0);
+/// envelope.note_on();
+///
+/// // the peak is reached after the attack time of 48 samples
+/// let peak = (0..48).map(|_| envelope.next()).last().unwrap();
+/// assert_eq!(peak, 1.0);
+/// assert_eq!(envelope.stage(), AdsrStage::Decay);
+/// ```
+pub struct Adsr {
+    smoother: TinySmoother,
+    stage: AdsrStage,
+    attack_beta: f64,
+    decay_beta: f64,
+    sustain: f32,
+    release_beta: f64,
+}
+
+impl Adsr {
+    /// Creates an idle envelope.
+    ///
+    /// # Parameters
+    /// * `sample_rate` - Sample rate in Hz
+    /// * `attack_ms` - Time in milliseconds from silence to the peak level 1.0
+    /// * `decay_ms` - Time in milliseconds in which the level falls halfway from the peak
+    ///   to the sustain level (half-life)
+    /// * `sustain` - Level held while the note is on, in range [0.0, 1.0]
+    /// * `release_ms` - Time in milliseconds in which the level falls by half after
+    ///   `note_off()` (half-life)
+    ///
+    /// # Panics
+    ///
+    /// Panics if a time is negative or not finite, or if `sustain` is not in range [0.0, 1.0].
+    pub fn new(sample_rate: f32, attack_ms: f32, decay_ms: f32, sustain: f32, release_ms: f32) -> Adsr {
+        for (name, ms) in [("Attack", attack_ms), ("Decay", decay_ms), ("Release", release_ms)] {
+            assert!(
+                ms.is_finite() && ms >= 0.0,
+                "{} time must be finite and non-negative, got {}",
+                name,
+                ms
+            );
+        }
+        assert!(
+            (0.0..=1.0).contains(&sustain),
+            "Sustain must be in range [0.0, 1.0], got {}",
+            sustain
+        );
+        let mut smoother = TinySmoother::new(0.0, 0.0);
+        smoother.set_snap_threshold(MIN_GAIN);
+        Adsr {
+            smoother,
+            stage: AdsrStage::Idle,
+            attack_beta: beta_from_half_life(ms_to_samples(sample_rate, attack_ms)),
+            decay_beta: beta_from_half_life(ms_to_samples(sample_rate, decay_ms)),
+            sustain,
+            release_beta: beta_from_half_life(ms_to_samples(sample_rate, release_ms)),
+        }
+    }
+
+    /// Starts the attack from the current level, so retriggering a sounding note does not
+    /// click.
+    pub fn note_on(&mut self) {
+        self.stage = AdsrStage::Attack;
+        self.smoother.set_beta(self.attack_beta);
+        self.smoother.set_target(ATTACK_TARGET);
+    }
+
+    /// Starts the release from the current level, in whatever stage the envelope is.
+    pub fn note_off(&mut self) {
+        if self.stage == AdsrStage::Idle {
+            return;
+        }
+        self.stage = AdsrStage::Release;
+        self.smoother.set_beta(self.release_beta);
+        self.smoother.set_target(0.0);
+    }
+
+    /// Returns the next envelope value and advances the stage when a segment is complete.
+    // Not an `Iterator`: the envelope never ends, it idles at 0.0.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> f32 {
+        match self.stage {
+            AdsrStage::Idle => 0.0,
+            AdsrStage::Attack => {
+                let value = self.smoother.tick();
+                if value < 1.0 {
+                    return value;
+                }
+                self.smoother.reset_to(1.0);
+                self.stage = AdsrStage::Decay;
+                self.smoother.set_beta(self.decay_beta);
+                self.smoother.set_target(self.sustain);
+                1.0
+            }
+            AdsrStage::Decay => {
+                let value = self.smoother.tick();
+                if !self.smoother.is_smoothing() {
+                    self.stage = AdsrStage::Sustain;
+                }
+                value
+            }
+            AdsrStage::Sustain => self.sustain,
+            AdsrStage::Release => {
+                let value = self.smoother.tick();
+                if !self.smoother.is_smoothing() {
+                    self.stage = AdsrStage::Idle;
+                }
+                value
+            }
+        }
+    }
+
+    /// Returns the current stage.
+    pub fn stage(&self) -> AdsrStage {
+        self.stage
+    }
+
+    /// Returns `true` unless the envelope is idle, i.e. while the voice must be rendered.
+    pub fn is_active(&self) -> bool {
+        self.stage != AdsrStage::Idle
+    }
+
+    /// Silences the envelope immediately.
+    pub fn reset(&mut self) {
+        self.smoother.reset();
+        self.stage = AdsrStage::Idle;
+    }
+}
+
+//--- Tests ---------------------------------------------------------------------------------------
+//
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 48 samples attack, 480 samples decay, 0.5 sustain, 960 samples release
+    fn envelope() -> Adsr {
+        Adsr::new(48_000.0, 1.0, 10.0, 0.5, 20.0)
+    }
+
+    #[test]
+    fn adsr_runs_through_all_stages() {
+        let mut env = envelope();
+        assert_eq!(env.next(), 0.0);
+        assert!(!env.is_active());
+
+        env.note_on();
+        for _ in 0..47 {
+            assert!(env.next() < 1.0);
+            assert_eq!(env.stage(), AdsrStage::Attack);
+        }
+        assert_eq!(env.next(), 1.0);
+        assert_eq!(env.stage(), AdsrStage::Decay);
+
+        // one decay half-life later, halfway between peak and sustain
+        let value = (0..480).map(|_| env.next()).last().unwrap();
+        assert!((value - 0.75).abs() < 1e-3);
+
+        let samples = (1..).find(|_| env.next() == 0.5).unwrap();
+        assert!(samples < 480 * 20);
+        assert_eq!(env.stage(), AdsrStage::Sustain);
+        assert_eq!(env.next(), 0.5);
+
+        env.note_off();
+        assert_eq!(env.stage(), AdsrStage::Release);
+        let value = (0..960).map(|_| env.next()).last().unwrap();
+        assert!((value - 0.25).abs() < 1e-3);
+
+        (0..960 * 20).for_each(|_| {
+            env.next();
+        });
+        assert_eq!(env.stage(), AdsrStage::Idle);
+        assert_eq!(env.next(), 0.0);
+    }
+
+    #[test]
+    fn note_off_during_attack_releases_from_current_level() {
+        let mut env = envelope();
+        env.note_on();
+        let mut last = (0..20).map(|_| env.next()).last().unwrap();
+        assert!(last > 0.0 && last < 1.0);
+
+        env.note_off();
+        for _ in 0..1000 {
+            let value = env.next();
+            assert!(value <= last && last - value < 0.01);
+            last = value;
+        }
+    }
+
+    #[test]
+    fn retrigger_starts_attack_from_current_level() {
+        let mut env = envelope();
+        env.note_on();
+        (0..2000).for_each(|_| {
+            env.next();
+        });
+        env.note_off();
+        let before = (0..100).map(|_| env.next()).last().unwrap();
+
+        env.note_on();
+        let after = env.next();
+        assert!(after > before && after - before < 0.05);
+    }
+
+    #[test]
+    fn zero_attack_jumps_to_peak() {
+        let mut env = Adsr::new(48_000.0, 0.0, 10.0, 0.5, 10.0);
+        env.note_on();
+        assert_eq!(env.next(), 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Sustain must be in range [0.0, 1.0]")]
+    fn adsr_panics_on_invalid_sustain() {
+        let _env = Adsr::new(48_000.0, 1.0, 10.0, 1.5, 10.0);
+    }
+}
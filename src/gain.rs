@@ -0,0 +1,341 @@
+//! A type-safe linear gain factor.
+//!
+//! Passing bare `f32` values around loses the unit: is `0.5` a linear factor or a
+//! (very small) dB value? `Gain` always holds the linear factor and converts from and to
+//! decibels explicitly.
+//!
+//! # Examples
+//!
+//! ```
+//! use audio_utils::Gain;
+//!
+//! let gain = Gain::from_db(-6.0);
+//! let sample = 0.8f32 * gain;
+//!
+//! assert!(sample > 0.400 && sample < 0.402);
+//! ```
+//!
+//! Its counterpart `Decibels` holds a level in dB; both convert into each other:
+//!
+//! ```
+//! use audio_utils::{Decibels, Gain};
+//!
+//! let gain: Gain = Decibels(-6.0).into();
+//! ```
+
+use crate::decibels::{db_to_volt_f, gain_to_db, volt_to_db_f, ToDb, MAX_GAIN};
+use std::ops::{Add, Mul, Sub};
+use std::str::FromStr;
+
+/// A linear gain factor ("voltage ratio"), e.g. `1.0` for unity gain, `0.5` for ~-6 dB.
+///
+/// The conversions from and to decibels use the interpolated lookup table conversions
+/// `db_to_volt_f()` and `volt_to_db_f()`, so they are clamped to the range [-100, 27] dB.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Gain(pub f32);
+
+impl Gain {
+    /// Creates a gain from a decibel value.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::Gain;
+    ///
+    /// assert_eq!(Gain::from_db(0.0).as_linear(), 1.0);
+    /// ```
+    #[inline]
+    pub fn from_db(db: f32) -> Gain {
+        Gain(db_to_volt_f(db))
+    }
+
+    /// Creates a gain from a linear factor.
+    #[inline]
+    pub const fn from_linear(v: f32) -> Gain {
+        Gain(v)
+    }
+
+    /// Returns the linear factor.
+    #[inline]
+    pub const fn as_linear(self) -> f32 {
+        self.0
+    }
+
+    /// Returns the gain in decibels, clamped to [-100, 27] dB. A gain of 0.0 (silence)
+    /// returns `f32::NEG_INFINITY`, see `volt_to_db_f()`.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::Gain;
+    ///
+    /// assert_eq!(Gain::from_linear(0.001).as_db(), -60.0);
+    /// ```
+    #[inline]
+    pub fn as_db(self) -> f32 {
+        volt_to_db_f(self.0)
+    }
+
+    /// Returns the gain in whole dB (`i32`) or fractional dB (`f32`), depending on the
+    /// requested output type, see `gain_to_db()`.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::Gain;
+    ///
+    /// let gain = Gain::from_linear(0.49);
+    ///
+    /// assert_eq!(gain.to_db::<i32>(), -6);
+    /// assert_eq!(gain.to_db::<f32>(), gain.as_db());
+    /// ```
+    #[inline]
+    pub fn to_db<T: ToDb>(self) -> T {
+        gain_to_db(self.0)
+    }
+}
+
+/// `gain * sample`
+impl Mul<f32> for Gain {
+    type Output = f32;
+
+    #[inline]
+    fn mul(self, sample: f32) -> f32 {
+        self.0 * sample
+    }
+}
+
+/// `sample * gain`
+impl Mul<Gain> for f32 {
+    type Output = f32;
+
+    #[inline]
+    fn mul(self, gain: Gain) -> f32 {
+        self * gain.0
+    }
+}
+
+/// Chaining two gain stages multiplies their linear factors.
+impl Mul for Gain {
+    type Output = Gain;
+
+    #[inline]
+    fn mul(self, other: Gain) -> Gain {
+        Gain(self.0 * other.0)
+    }
+}
+
+/// A level in decibels, e.g. `Decibels(-6.0)`.
+///
+/// Adding decibels multiplies the corresponding gains, so `Add`/`Sub` are the natural
+/// arithmetic. Conversions from and to `Gain` use `db_to_volt_f()` and `volt_to_db_f()`,
+/// and are therefore clamped to the range [-100, 27] dB; `f32::NEG_INFINITY` stands for
+/// silence in both directions. Levels can be parsed from strings like `"-6.0"` or `"-inf"`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Decibels(pub f32);
+
+impl From<Decibels> for Gain {
+    /// # Example
+    /// ```
+    /// use audio_utils::{Decibels, Gain};
+    ///
+    /// let gain: Gain = Decibels(-60.0).into();
+    ///
+    /// assert_eq!(gain, Gain(0.001));
+    /// ```
+    #[inline]
+    fn from(db: Decibels) -> Gain {
+        if db.0 == f32::NEG_INFINITY {
+            return Gain(0.0);
+        }
+        if db.0 == f32::INFINITY {
+            return Gain(MAX_GAIN);
+        }
+        Gain::from_db(db.0)
+    }
+}
+
+impl FromStr for Decibels {
+    type Err = ParseDecibelsError;
+
+    /// Parses a dB value as stored in presets and config files, e.g. `"-6.0"`, `"-6 dB"` or
+    /// `"-inf"` for silence.
+    ///
+    /// Surrounding whitespace and a trailing `dB` unit (in any case) are ignored. The
+    /// tokens `-inf` and `inf` (also `infinity`) yield `f32::NEG_INFINITY` and
+    /// `f32::INFINITY`, which convert into silence and the maximum gain. `NaN` is rejected.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::{Decibels, Gain};
+    ///
+    /// let level: Decibels = "-6.0".parse().unwrap();
+    /// assert_eq!(level, Decibels(-6.0));
+    ///
+    /// let muted: Decibels = "-inf dB".parse().unwrap();
+    /// assert_eq!(Gain::from(muted), Gain(0.0));
+    ///
+    /// assert!("loud".parse::<Decibels>().is_err());
+    /// ```
+    fn from_str(text: &str) -> Result<Decibels, ParseDecibelsError> {
+        let trimmed = text.trim();
+        let unit_at = trimmed.len().checked_sub(2);
+        let number = match unit_at.and_then(|at| trimmed.split_at_checked(at)) {
+            Some((number, unit)) if unit.eq_ignore_ascii_case("db") => number.trim_end(),
+            _ => trimmed,
+        };
+        match number.parse::<f32>() {
+            Ok(db) if !db.is_nan() => Ok(Decibels(db)),
+            _ => Err(ParseDecibelsError(text.to_owned())),
+        }
+    }
+}
+
+/// Error returned when parsing `Decibels` from a string fails, holding the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDecibelsError(pub String);
+
+impl std::fmt::Display for ParseDecibelsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid dB value {:?}, expected a number like \"-6.0\" or \"-inf\"", self.0)
+    }
+}
+
+impl std::error::Error for ParseDecibelsError {}
+
+impl From<Gain> for Decibels {
+    #[inline]
+    fn from(gain: Gain) -> Decibels {
+        Decibels(gain.as_db())
+    }
+}
+
+/// Adding decibels multiplies the linear gains.
+impl Add for Decibels {
+    type Output = Decibels;
+
+    #[inline]
+    fn add(self, other: Decibels) -> Decibels {
+        Decibels(self.0 + other.0)
+    }
+}
+
+/// Subtracting decibels divides the linear gains.
+impl Sub for Decibels {
+    type Output = Decibels;
+
+    #[inline]
+    fn sub(self, other: Decibels) -> Decibels {
+        Decibels(self.0 - other.0)
+    }
+}
+
+//--- Tests ---------------------------------------------------------------------------------------
+//
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gain_from_db_uses_table_values() {
+        assert_eq!(Gain::from_db(0.0).as_linear(), 1.0);
+        assert_eq!(Gain::from_db(-60.0).as_linear(), 0.001);
+    }
+
+    #[test]
+    fn gain_from_linear_keeps_the_value() {
+        assert_eq!(Gain::from_linear(0.25).as_linear(), 0.25);
+    }
+
+    #[test]
+    fn gain_as_db_is_inverse_of_from_db() {
+        for db in [-90.0f32, -42.5, -6.0, -0.25, 0.0, 3.3, 20.0] {
+            let actual = Gain::from_db(db).as_db();
+            assert!((actual - db).abs() < 1e-3, "db = {db}, actual = {actual}");
+        }
+    }
+
+    #[test]
+    fn gain_as_db_distinguishes_silence() {
+        assert_eq!(Gain::from_linear(0.0).as_db(), f32::NEG_INFINITY);
+        assert_eq!(Gain::from_linear(1e-6).as_db(), -100.0);
+    }
+
+    #[test]
+    fn gain_to_db_supports_whole_and_fractional_db() {
+        let gain = Gain::from_db(-6.4);
+        let whole: i32 = gain.to_db();
+        assert_eq!(whole, -6);
+        assert!((gain.to_db::<f32>() + 6.4).abs() < 1e-3);
+        assert_eq!(Gain::from_linear(0.0).to_db::<i32>(), -100);
+    }
+
+    #[test]
+    fn gain_multiplies_samples_from_both_sides() {
+        let gain = Gain::from_linear(0.5);
+        assert_eq!(0.8f32 * gain, 0.4);
+        assert_eq!(gain * 0.8f32, 0.4);
+    }
+
+    #[test]
+    fn gains_multiply_to_a_gain() {
+        let total = Gain::from_linear(0.5) * Gain::from_linear(4.0);
+        assert_eq!(total, Gain::from_linear(2.0));
+    }
+
+    //--- Decibels
+    #[test]
+    fn adding_6_db_doubles_the_gain() {
+        for db in [-40.0f32, -12.5, -6.0, 0.0, 10.0] {
+            let before = Gain::from(Decibels(db)).as_linear();
+            let after = Gain::from(Decibels(db) + Decibels(6.0)).as_linear();
+            // 6 dB is a factor of 1.995, not exactly 2
+            assert!((after / before - 2.0).abs() < 0.01, "db = {db}");
+        }
+        assert_eq!(Decibels(-3.0) - Decibels(3.0), Decibels(-6.0));
+    }
+
+    #[test]
+    fn decibels_and_gain_round_trip() {
+        for db in [-90.0f32, -42.5, -6.0, 0.0, 3.3, 20.0] {
+            let actual: Decibels = Gain::from(Decibels(db)).into();
+            assert!((actual.0 - db).abs() < 1e-3, "db = {db}, actual = {actual:?}");
+        }
+        for linear in [0.001f32, 0.3, 1.0, 5.0] {
+            let actual: Gain = Decibels::from(Gain(linear)).into();
+            assert!((actual.0 / linear - 1.0).abs() < 1e-4, "linear = {linear}");
+        }
+    }
+
+    #[test]
+    fn silence_round_trips_through_decibels() {
+        let db: Decibels = Gain(0.0).into();
+        assert_eq!(db, Decibels(f32::NEG_INFINITY));
+        assert_eq!(Gain::from(db), Gain(0.0));
+    }
+
+    #[test]
+    fn decibels_parse_from_config_strings() {
+        assert_eq!("-6.0".parse(), Ok(Decibels(-6.0)));
+        assert_eq!(" 3 ".parse(), Ok(Decibels(3.0)));
+        assert_eq!("-12.5 dB".parse(), Ok(Decibels(-12.5)));
+        assert_eq!("-12.5DB".parse(), Ok(Decibels(-12.5)));
+        assert_eq!("+1.5e1".parse(), Ok(Decibels(15.0)));
+
+        let muted: Decibels = "-inf".parse().unwrap();
+        assert_eq!(muted, Decibels(f32::NEG_INFINITY));
+        assert_eq!(Gain::from(muted), Gain(0.0));
+        let loudest: Decibels = "inf".parse().unwrap();
+        assert_eq!(Gain::from(loudest), Gain(MAX_GAIN));
+    }
+
+    #[test]
+    fn decibels_parse_rejects_malformed_input() {
+        for text in ["", "dB", "loud", "-6..0", "NaN", "-6 dBFS", "6 d", "Ödb"] {
+            assert_eq!(
+                text.parse::<Decibels>(),
+                Err(ParseDecibelsError(text.to_owned())),
+                "text = {text:?}"
+            );
+        }
+        let message = "x".parse::<Decibels>().unwrap_err().to_string();
+        assert_eq!(message, r#"Invalid dB value "x", expected a number like "-6.0" or "-inf""#);
+    }
+}
@@ -0,0 +1,260 @@
+//! Deterministic gain ramps of a known length for fades and declicking.
+//!
+//! Unlike `TinySmoother`, which approaches its target asymptotically, a `GainRamp` reaches
+//! its end value exactly after a given number of samples. This is what fade-ins, fade-outs
+//! and declicking at playback boundaries need.
+
+use crate::decibels::{db_to_volt_f, volt_to_db_f, DB_VOLT_LOOKUP_MIN};
+
+/// The curve along which a `GainRamp` moves from its start to its end value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RampShape {
+    /// Linear in the gain domain.
+    #[default]
+    Linear,
+    /// Linear in the dB domain, converted with `db_to_volt_f()`. This sounds even for long
+    /// fades, but the dB range is limited to [-100, 27] dB by the lookup table, and the start
+    /// and end gains must not be negative (dB carry no sign).
+    Decibels,
+}
+
+/// A gain ramp from a start to an end value over an exact number of samples.
+///
+/// # Example
+/// ```
+/// use audio_utils::{GainRamp, RampShape};
+///
+/// let mut fade_in = GainRamp::new(RampShape::Linear);
+/// fade_in.arm(0.0, 1.0, 5);
+///
+/// let values: Vec<f32> = (0..5).map(|_| fade_in.next()).collect();
+/// assert_eq!(values, [0.0, 0.25, 0.5, 0.75, 1.0]);
+/// assert!(fade_in.is_finished());
+/// ```
+pub struct GainRamp {
+    shape: RampShape,
+    start: f32,
+    end: f32,
+    /// Start and end in dB, only used by `RampShape::Decibels`
+    start_db: f32,
+    end_db: f32,
+    /// Number of values the ramp produces
+    length: u32,
+    /// Number of values produced so far
+    position: u32,
+}
+
+impl Default for GainRamp {
+    /// Creates a finished linear ramp that holds unity gain.
+    fn default() -> GainRamp {
+        GainRamp::new(RampShape::Linear)
+    }
+}
+
+impl GainRamp {
+    /// Creates a finished ramp with the given shape that holds unity gain until it is armed.
+    pub fn new(shape: RampShape) -> GainRamp {
+        GainRamp {
+            shape,
+            start: 1.0,
+            end: 1.0,
+            start_db: 0.0,
+            end_db: 0.0,
+            length: 0,
+            position: 0,
+        }
+    }
+
+    /// Starts a new ramp from `start` to `end`.
+    ///
+    /// The ramp produces `length_samples` values: the first one is exactly `start`, the last
+    /// one exactly `end`. Afterwards, `next()` keeps returning `end`. With a length of 0 or 1,
+    /// the ramp jumps to `end` immediately.
+    ///
+    /// Non-finite `start` or `end` values are replaced by unity gain. A negative gain with
+    /// `RampShape::Decibels` is a caller bug: it fails a debug assertion, in release builds
+    /// its magnitude is used, so the ramp doesn't flip its sign after the first value.
+    pub fn arm(&mut self, start: f32, end: f32, length_samples: u32) {
+        self.start = if start.is_finite() { start } else { 1.0 };
+        self.end = if end.is_finite() { end } else { 1.0 };
+        if self.shape == RampShape::Decibels {
+            debug_assert!(
+                self.start >= 0.0 && self.end >= 0.0,
+                "Decibels ramp needs non-negative gains, got {} to {}",
+                self.start,
+                self.end
+            );
+            self.start = self.start.abs();
+            self.end = self.end.abs();
+        }
+        // silence (-inf dB) ramps from/to the lower end of the table instead
+        self.start_db = volt_to_db_f(self.start).max(DB_VOLT_LOOKUP_MIN as f32);
+        self.end_db = volt_to_db_f(self.end).max(DB_VOLT_LOOKUP_MIN as f32);
+        self.length = length_samples;
+        self.position = 0;
+        if length_samples <= 1 {
+            self.position = length_samples;
+            self.start = self.end;
+        }
+    }
+
+    /// Returns the next gain value of the ramp, or the end value once it is finished.
+    // Not an `Iterator`: the ramp never ends, it holds the end value.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> f32 {
+        if self.is_finished() {
+            return self.end;
+        }
+        let position = self.position;
+        self.position += 1;
+
+        if position == 0 {
+            return self.start;
+        }
+        if position == self.length - 1 {
+            return self.end;
+        }
+        let t = position as f32 / (self.length - 1) as f32;
+        match self.shape {
+            RampShape::Linear => self.start * (1.0 - t) + self.end * t,
+            RampShape::Decibels => db_to_volt_f(self.start_db * (1.0 - t) + self.end_db * t),
+        }
+    }
+
+    /// Returns `true` once all values of the ramp have been produced.
+    pub fn is_finished(&self) -> bool {
+        self.position >= self.length
+    }
+
+    /// Returns the number of values left until the ramp is finished.
+    pub fn remaining_samples(&self) -> u32 {
+        self.length - self.position.min(self.length)
+    }
+}
+
+//--- Tests ---------------------------------------------------------------------------------------
+//
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect(ramp: &mut GainRamp, count: usize) -> Vec<f32> {
+        (0..count).map(|_| ramp.next()).collect()
+    }
+
+    #[test]
+    fn ramp_hits_exact_start_and_end_values() {
+        for shape in [RampShape::Linear, RampShape::Decibels] {
+            let mut ramp = GainRamp::new(shape);
+            ramp.arm(0.3, 0.7, 100);
+            let values = collect(&mut ramp, 100);
+            assert_eq!(values[0], 0.3, "{shape:?}");
+            assert_eq!(values[99], 0.7, "{shape:?}");
+        }
+    }
+
+    #[test]
+    fn ramp_has_exact_length() {
+        let mut ramp = GainRamp::default();
+        ramp.arm(0.0, 1.0, 480);
+        assert_eq!(ramp.remaining_samples(), 480);
+
+        for _ in 0..479 {
+            ramp.next();
+            assert!(!ramp.is_finished());
+        }
+        assert_eq!(ramp.next(), 1.0);
+        assert!(ramp.is_finished());
+        assert_eq!(ramp.remaining_samples(), 0);
+    }
+
+    #[test]
+    fn ramp_holds_end_value_when_finished() {
+        let mut ramp = GainRamp::default();
+        ramp.arm(1.0, 0.0, 10);
+        collect(&mut ramp, 10);
+        for _ in 0..100 {
+            assert_eq!(ramp.next(), 0.0);
+        }
+    }
+
+    #[test]
+    fn ramp_is_finished_transition() {
+        let mut ramp = GainRamp::default();
+        assert!(ramp.is_finished());
+        assert_eq!(ramp.next(), 1.0);
+
+        ramp.arm(1.0, 0.5, 2);
+        assert!(!ramp.is_finished());
+        ramp.next();
+        assert!(!ramp.is_finished());
+        ramp.next();
+        assert!(ramp.is_finished());
+    }
+
+    #[test]
+    fn ramp_is_linear_in_gain() {
+        let mut ramp = GainRamp::new(RampShape::Linear);
+        ramp.arm(0.0, 1.0, 11);
+        for (i, value) in collect(&mut ramp, 11).into_iter().enumerate() {
+            assert!((value - i as f32 / 10.0).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn ramp_is_linear_in_db() {
+        let mut ramp = GainRamp::new(RampShape::Decibels);
+        ramp.arm(0.001, 1.0, 61);
+        for (i, value) in collect(&mut ramp, 61).into_iter().enumerate() {
+            let expected_db = -60.0 + i as f32;
+            assert!((volt_to_db_f(value) - expected_db).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn ramp_in_db_from_silence_stays_quiet() {
+        let mut ramp = GainRamp::new(RampShape::Decibels);
+        ramp.arm(0.0, 1.0, 101);
+        let values = collect(&mut ramp, 101);
+        assert_eq!(values[0], 0.0);
+        assert!(values[1] < 1e-4);
+        assert!(values.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Decibels ramp needs non-negative gains")]
+    fn ramp_in_db_panics_on_negative_gain_in_debug_builds() {
+        GainRamp::new(RampShape::Decibels).arm(-0.5, 1.0, 10);
+    }
+
+    #[test]
+    #[cfg(not(debug_assertions))]
+    fn ramp_in_db_uses_the_magnitude_of_negative_gains() {
+        let mut ramp = GainRamp::new(RampShape::Decibels);
+        ramp.arm(-0.001, -1.0, 61);
+        let values = collect(&mut ramp, 61);
+        assert_eq!(values[0], 0.001);
+        assert_eq!(values[60], 1.0);
+        assert!(values.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[test]
+    fn ramp_in_gain_keeps_negative_gains() {
+        let mut ramp = GainRamp::new(RampShape::Linear);
+        ramp.arm(-1.0, 1.0, 3);
+        assert_eq!(collect(&mut ramp, 3), [-1.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn ramp_with_length_zero_or_one_jumps_to_end() {
+        let mut ramp = GainRamp::default();
+        ramp.arm(0.0, 0.5, 0);
+        assert!(ramp.is_finished());
+        assert_eq!(ramp.next(), 0.5);
+
+        ramp.arm(0.0, 0.25, 1);
+        assert!(ramp.is_finished());
+        assert_eq!(ramp.next(), 0.25);
+    }
+}
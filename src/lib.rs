@@ -4,13 +4,94 @@
 //!
 //! This crate provides:
 //! - Fast dB/gain conversions via lookup tables
+//! - Fader taper curves mapping a fader position to dB
 //! - Smooth parameter transitions with drift-free exponential smoothing
+//! - A `Smoother` trait for code generic over the smoother types
+//! - An f32-only smoother for bit-identical output across platforms
+//! - Smoothing arrays of values, e.g. per-band gains, with one shared coefficient
+//! - Two cascaded one-poles for a gentle S-shaped onset
+//! - Asymmetric attack/release smoothing for envelope followers and dynamics
+//! - One Euro filtering for noisy control signals
+//! - Smoothing of frequencies and pitch in log2 (octave) space
+//! - Gain ramps of exact length for fades and declicking
+//! - An ADSR envelope with analog-style exponential segments
+//! - A type-safe `Gain` newtype for linear gain factors
+//! - A smoothed gain parameter set in dB
+//! - Soft and hard clipping against a ceiling
+//! - Pan laws returning left/right gains
+//! - A smoothed equal-power crossfader, and table-based equal-power crossfade gains
+//! - Smoothed gain pairs for pan, balance and stereo width
+//! - Declicked bypass switching
+//! - Peak, RMS and VU/PPM metering in dB, and a latching clip indicator
+//! - Applying smoothed gains to multi-channel buffers
+//!
+//! `use audio_utils::prelude::*;` imports the conversion and smoother traits together with
+//! the most used functions and types.
 //!
 //! All implementations are optimised for real-time audio with minimal allocations
 //! and predictable performance characteristics.
 
+pub mod smoother;
 pub mod tiny_smoother;
+pub mod tiny_smoother_f32;
+pub mod array_smoother;
+pub mod cascade_smoother;
+pub mod attack_release;
+pub mod one_euro;
+pub mod log2_smoother;
+pub mod gain_ramp;
+pub mod envelope;
 pub mod decibels;
+pub mod gain;
+pub mod smoothed_gain;
+pub mod clip;
+pub mod pan;
+pub mod crossfader;
+pub mod coupled_pair;
+pub mod bypass;
+pub mod meter;
+pub mod buffer;
+pub mod prelude;
+#[cfg(feature = "simd")]
+pub mod tiny_smoother4;
+#[cfg(feature = "simd")]
+mod simd;
 
-pub use tiny_smoother::TinySmoother;
-pub use decibels::{db_to_volt, volt_to_db, DbToVolt, VoltToDb};
\ No newline at end of file
+pub use smoother::Smoother;
+pub use tiny_smoother::{
+    SmootherError, SmoothingCoeffs, StepResponse, TinySmoother, TinySmootherBuilder,
+};
+pub use tiny_smoother_f32::TinySmootherF32;
+pub use array_smoother::ArraySmoother;
+pub use cascade_smoother::Cascade2Smoother;
+pub use attack_release::AttackReleaseSmoother;
+pub use one_euro::OneEuroSmoother;
+pub use log2_smoother::Log2Smoother;
+pub use gain_ramp::{GainRamp, RampShape};
+pub use envelope::{Adsr, AdsrStage};
+pub use decibels::{
+    a_weight_db, a_weight_gain, assert_gain_close, cc_to_gain, db_boundary_gain, db_difference,
+    db_sum, db_to_volt, db_to_volt_checked, db_to_volt_exact, db_to_volt_f, db_to_volt_slice,
+    db_to_volt_with, dbfs_to_gain, fader_to_db, fader_to_gain, gain_add_db, gain_sum_db, gain_to_db,
+    gain_to_dbfs, headroom_db, pcm_to_dbfs, peak_normalize_gain, sample_to_dbfs, snap_gain,
+    supported_db_range, table_entries, velocity_to_gain, volt_to_db, volt_to_db_any,
+    volt_to_db_ceil, volt_to_db_f, volt_to_db_floor, volt_to_db_slice, volt_to_db_slice_f,
+    volt_to_db_with, DbConvention, DbToVolt, FaderLaw, PcmSample, ToDb, VoltToDb,
+    CONVERSION_REL_TOLERANCE, MAX_DB, MAX_GAIN, MIN_DB, MIN_GAIN,
+};
+pub use gain::{Decibels, Gain, ParseDecibelsError};
+pub use smoothed_gain::SmoothedGain;
+pub use clip::{hard_clip, hard_clip_db, soft_clip, soft_clip_db};
+pub use pan::{equal_power_pan, pan_gains, PanLaw};
+pub use crossfader::{crossfade_gains, Crossfader, CROSSFADE_LUT};
+pub use coupled_pair::{CoupledPair, PairLaw};
+pub use bypass::BypassFade;
+pub use meter::{
+    peak_db, rms_db, Ballistics, ClipIndicator, DbDisplayDither, Meter, PeakMeter, RmsMeter,
+    StereoPeakMeter,
+};
+pub use buffer::{apply_smoothed_gain, scale_block, scale_sample};
+#[cfg(feature = "simd")]
+pub use buffer::apply_gain_simd;
+#[cfg(feature = "simd")]
+pub use tiny_smoother4::TinySmoother4;
\ No newline at end of file
@@ -0,0 +1,161 @@
+//! Smoothing of frequencies and other ratio parameters in log2 (octave) space.
+//!
+//! A linear smoother glides from 100 Hz to 400 Hz through 250 Hz at its half-life, which
+//! sounds rushed at the start and slow at the end. `Log2Smoother` smooths `log2(value)`
+//! instead and returns `exp2()` of the result, so a glide moves by equal musical intervals
+//! (semitones, octaves) per unit of time and passes through the geometric midpoint, 200 Hz.
+//!
+//! # Examples
+//!
+//! ```
+//! use audio_utils::Log2Smoother;
+//!
+//! let mut cutoff = Log2Smoother::new(48_000.0, 20.0, 1000.0);
+//!
+//! // per sample, from the cutoff parameter in Hz
+//! let hz = cutoff.next(5000.0);
+//! assert!(hz > 1000.0 && hz < 5000.0);
+//! ```
+
+use crate::tiny_smoother::{beta_from_half_life, ms_to_samples, TinySmoother};
+use crate::smoother::Smoother;
+
+/// A positive value smoothed in log2 space, e.g. a frequency or pitch.
+pub struct Log2Smoother {
+    /// Smooths `log2(value)`
+    smoother: TinySmoother,
+}
+
+impl Log2Smoother {
+    /// Creates a smoother resting at `start_value`.
+    ///
+    /// # Parameters
+    /// * `sample_rate` - Sample rate in Hz
+    /// * `half_life_ms` - Time in milliseconds after which a glide has covered half of its
+    ///   interval in octaves (0.0 for no smoothing)
+    /// * `start_value` - Value after creation and `reset()`, e.g. in Hz
+    ///
+    /// # Panics
+    ///
+    /// Panics if `half_life_ms` is negative, or if `start_value` is not positive and finite.
+    pub fn new(sample_rate: f32, half_life_ms: f32, start_value: f32) -> Log2Smoother {
+        assert!(
+            start_value > 0.0 && start_value.is_finite(),
+            "Start value must be positive and finite, got {}",
+            start_value
+        );
+        let beta = beta_from_half_life(ms_to_samples(sample_rate, half_life_ms));
+        Log2Smoother {
+            smoother: TinySmoother::new(beta, start_value.log2()),
+        }
+    }
+
+    /// Processes the next target value and returns the smoothed value.
+    ///
+    /// Targets that are not positive and finite (zero, negative, infinite, `NaN`) have no
+    /// logarithm and are ignored, keeping the current value.
+    ///
+    /// # Real-time safety
+    ///
+    /// Never panics and never allocates, for any input including `NaN` and infinities.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self, target: f32) -> f32 {
+        let log_value = if target > 0.0 && target.is_finite() {
+            self.smoother.next_f64((target as f64).log2())
+        } else {
+            self.smoother.current_value_f64()
+        };
+        log_value.exp2() as f32
+    }
+
+    /// Returns `true` while the value is still moving toward the target.
+    pub fn is_smoothing(&self) -> bool {
+        self.smoother.is_smoothing()
+    }
+
+    /// Jumps back to the start value without smoothing.
+    pub fn reset(&mut self) {
+        self.smoother.reset();
+    }
+}
+
+impl Smoother for Log2Smoother {
+    #[inline]
+    fn next(&mut self, target: f32) -> f32 {
+        Log2Smoother::next(self, target)
+    }
+
+    fn reset(&mut self) {
+        Log2Smoother::reset(self);
+    }
+
+    fn current_value(&self) -> f32 {
+        self.smoother.current_value_f64().exp2() as f32
+    }
+}
+
+//--- Tests ---------------------------------------------------------------------------------------
+//
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glide_passes_the_geometric_midpoint_at_the_half_life() {
+        // 1 ms at 48 kHz: a half-life of 48 samples
+        let mut log2 = Log2Smoother::new(48_000.0, 1.0, 100.0);
+        let mut linear = TinySmoother::new(beta_from_half_life(48.0), 100.0);
+
+        let mut hz = 0.0;
+        let mut linear_hz = 0.0;
+        for _ in 0..48 {
+            hz = log2.next(400.0);
+            linear_hz = linear.next(400.0);
+        }
+        assert!((hz - 200.0).abs() < 0.01, "hz = {hz}");
+        assert!((linear_hz - 250.0).abs() < 0.01, "linear_hz = {linear_hz}");
+    }
+
+    #[test]
+    fn glide_moves_by_equal_intervals() {
+        let mut smoother = Log2Smoother::new(48_000.0, 1.0, 100.0);
+        let mut last = 100.0f32;
+        let mut last_ratio = f32::INFINITY;
+        for _ in 0..480 {
+            let hz = smoother.next(6400.0);
+            // each step covers a constant fraction of the remaining interval in octaves,
+            // so the ratio per step decreases monotonically
+            let ratio = hz / last;
+            assert!(ratio > 1.0 && ratio <= last_ratio);
+            last_ratio = ratio;
+            last = hz;
+        }
+        // after 10 half-lives, 1/1024 of the 6 octaves is left
+        assert!((last / 6400.0 - 2.0f32.powf(-6.0 / 1024.0)).abs() < 1e-4);
+    }
+
+    #[test]
+    fn ignores_non_positive_and_non_finite_targets() {
+        let mut smoother = Log2Smoother::new(48_000.0, 1.0, 440.0);
+        let hz = smoother.next(880.0);
+        for target in [0.0, -440.0, f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+            assert_eq!(smoother.next(target), hz, "target = {target}");
+        }
+    }
+
+    #[test]
+    fn reset_returns_to_start() {
+        let mut smoother = Log2Smoother::new(48_000.0, 1.0, 440.0);
+        smoother.next(880.0);
+        assert!(smoother.is_smoothing());
+        smoother.reset();
+        assert!(!smoother.is_smoothing());
+        assert_eq!(smoother.next(440.0), 440.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Start value must be positive and finite, got 0")]
+    fn rejects_zero_start_value() {
+        Log2Smoother::new(48_000.0, 1.0, 0.0);
+    }
+}
@@ -0,0 +1,855 @@
+//! Level metering: reports the peak or RMS level of a block of samples in dB.
+//!
+//! Metering is the inverse use case of the gain table: instead of converting a dB value
+//! into a gain, the level of the signal is converted into dB via `volt_to_db_f()`.
+//! Silence is reported as -100 dB, the lower end of the lookup table.
+//!
+//! # Examples
+//!
+//! ```
+//! use audio_utils::peak_db;
+//!
+//! let block = [0.0f32, 0.5, -1.0, 0.25];
+//! assert_eq!(peak_db(&block), 0.0);
+//! ```
+
+use crate::attack_release::AttackReleaseSmoother;
+use crate::decibels::{db_to_volt_f, volt_to_db_f, DB_VOLT_LOOKUP_MAX, DB_VOLT_LOOKUP_MIN};
+use crate::tiny_smoother::{beta_from_half_life, ms_to_samples, TinySmoother};
+
+/// Returns the peak level of `buffer` in dB, i.e. the maximum absolute sample value
+/// converted via `volt_to_db_f()`.
+///
+/// # Returns
+///
+/// * `f32` decibel value in the range `[-100.0, 27.0]`, -100.0 for silence or an empty buffer.
+///
+/// # Example
+/// ```
+/// use audio_utils::peak_db;
+///
+/// assert_eq!(peak_db(&[0.0; 64]), -100.0);
+/// assert_eq!(peak_db(&[0.1, -0.001]), -20.0);
+/// ```
+pub fn peak_db(buffer: &[f32]) -> f32 {
+    level_to_db(peak(buffer))
+}
+
+/// Converts a linear level into dB, reporting silence as -100 dB instead of -inf.
+#[inline]
+pub(crate) fn level_to_db(level: f32) -> f32 {
+    volt_to_db_f(level).max(DB_VOLT_LOOKUP_MIN as f32)
+}
+
+/// Maximum absolute sample value, non-finite samples are ignored.
+#[inline]
+fn peak(buffer: &[f32]) -> f32 {
+    buffer
+        .iter()
+        .map(|sample| sample.abs())
+        .filter(|sample| sample.is_finite())
+        .fold(0.0, f32::max)
+}
+
+/// A peak meter with instant attack and smoothed decay.
+///
+/// Whenever a sample exceeds the current level, the level jumps to it. Otherwise the level
+/// decays exponentially toward the signal, using a `TinySmoother` on the linear peak.
+///
+/// # Example
+/// ```
+/// use audio_utils::PeakMeter;
+///
+/// let mut meter = PeakMeter::new(48_000.0, 300.0);
+/// meter.push_block(&[0.25, -0.5, 1.0]);
+///
+/// assert_eq!(meter.level_db(), 0.0);
+/// ```
+pub struct PeakMeter {
+    smoother: TinySmoother,
+    /// Current linear peak level
+    level: f32,
+}
+
+impl Default for PeakMeter {
+    /// Creates a peak meter with the decay of `TinySmoother::default()`
+    /// (~10ms half-life at 48 kHz).
+    fn default() -> PeakMeter {
+        PeakMeter {
+            smoother: TinySmoother::default(),
+            level: 0.0,
+        }
+    }
+}
+
+impl PeakMeter {
+    /// Creates a peak meter with the given decay time.
+    ///
+    /// # Parameters
+    /// * `sample_rate` - Sample rate in Hz
+    /// * `decay_ms` - Time in milliseconds in which the level falls by half (-6 dB)
+    ///
+    /// # Panics
+    ///
+    /// Panics if `decay_ms` is negative.
+    pub fn new(sample_rate: f32, decay_ms: f32) -> PeakMeter {
+        let beta = beta_from_half_life(ms_to_samples(sample_rate, decay_ms));
+        PeakMeter {
+            smoother: TinySmoother::new(beta, 0.0),
+            level: 0.0,
+        }
+    }
+
+    /// Feeds a block of samples into the meter. Non-finite samples are ignored.
+    pub fn push_block(&mut self, buf: &[f32]) {
+        for &sample in buf {
+            self.push_sample(sample);
+        }
+    }
+
+    /// Feeds a single sample into the meter. Non-finite samples are ignored.
+    #[inline]
+    fn push_sample(&mut self, sample: f32) {
+        let sample = sample.abs();
+        if !sample.is_finite() {
+            return;
+        }
+        if sample >= self.level {
+            self.smoother.reset_to(sample);
+            self.level = sample;
+        } else {
+            self.level = self.smoother.next(sample);
+        }
+    }
+
+    /// Returns the current level in dB, -100.0 for silence.
+    pub fn level_db(&self) -> f32 {
+        level_to_db(self.level)
+    }
+
+    /// Resets the meter to silence.
+    pub fn reset(&mut self) {
+        self.smoother.reset();
+        self.level = 0.0;
+    }
+}
+
+/// A stereo peak meter reporting both per-channel and linked levels.
+///
+/// Each channel is metered independently like a `PeakMeter`, with its own `TinySmoother`
+/// for the decay. The linked level is the maximum of both channels, which is what a
+/// stereo-linked limiter or a single "master" meter would show.
+///
+/// # Example
+/// ```
+/// use audio_utils::StereoPeakMeter;
+///
+/// let mut meter = StereoPeakMeter::new(48_000.0, 300.0);
+/// meter.push_frame(0.1, -1.0);
+///
+/// assert_eq!(meter.left_db(), -20.0);
+/// assert_eq!(meter.right_db(), 0.0);
+/// assert_eq!(meter.linked_db(), 0.0);
+/// ```
+#[derive(Default)]
+pub struct StereoPeakMeter {
+    left: PeakMeter,
+    right: PeakMeter,
+}
+
+impl StereoPeakMeter {
+    /// Creates a stereo peak meter with the given decay time for both channels.
+    ///
+    /// # Parameters
+    /// * `sample_rate` - Sample rate in Hz
+    /// * `decay_ms` - Time in milliseconds in which the level falls by half (-6 dB)
+    ///
+    /// # Panics
+    ///
+    /// Panics if `decay_ms` is negative.
+    pub fn new(sample_rate: f32, decay_ms: f32) -> StereoPeakMeter {
+        StereoPeakMeter {
+            left: PeakMeter::new(sample_rate, decay_ms),
+            right: PeakMeter::new(sample_rate, decay_ms),
+        }
+    }
+
+    /// Feeds one stereo frame into the meter. Non-finite samples are ignored.
+    #[inline]
+    pub fn push_frame(&mut self, l: f32, r: f32) {
+        self.left.push_sample(l);
+        self.right.push_sample(r);
+    }
+
+    /// Returns the current level of the left channel in dB, -100.0 for silence.
+    pub fn left_db(&self) -> f32 {
+        self.left.level_db()
+    }
+
+    /// Returns the current level of the right channel in dB, -100.0 for silence.
+    pub fn right_db(&self) -> f32 {
+        self.right.level_db()
+    }
+
+    /// Returns the louder of both channels in dB, -100.0 for silence.
+    pub fn linked_db(&self) -> f32 {
+        level_to_db(self.left.level.max(self.right.level))
+    }
+
+    /// Resets both channels to silence.
+    pub fn reset(&mut self) {
+        self.left.reset();
+        self.right.reset();
+    }
+}
+
+/// Returns the RMS level of `buffer` in dB, i.e. `sqrt(mean(x^2))` converted via
+/// `volt_to_db_f()`. Non-finite samples count as silence.
+///
+/// # Returns
+///
+/// * `f32` decibel value in the range `[-100.0, 27.0]`, -100.0 for silence or an empty buffer.
+///
+/// # Example
+/// ```
+/// use audio_utils::rms_db;
+///
+/// assert_eq!(rms_db(&[0.1, -0.1, 0.1, -0.1]), -20.0);
+/// ```
+pub fn rms_db(buffer: &[f32]) -> f32 {
+    if buffer.is_empty() {
+        return level_to_db(0.0);
+    }
+    let sum_of_squares: f64 = buffer.iter().map(|&sample| square(sample)).sum();
+    level_to_db((sum_of_squares / buffer.len() as f64).sqrt() as f32)
+}
+
+/// Square of a sample in f64, non-finite samples count as silence.
+#[inline]
+fn square(sample: f32) -> f64 {
+    if sample.is_finite() {
+        sample as f64 * sample as f64
+    } else {
+        0.0
+    }
+}
+
+/// An RMS meter over a sliding window of the most recent samples.
+///
+/// The meter maintains a running sum of squares: each new sample is added and the sample
+/// that falls out of the window is subtracted, so the cost per sample is constant regardless
+/// of the window length. Until the window is filled, the missing samples count as silence.
+///
+/// # Example
+/// ```
+/// use audio_utils::RmsMeter;
+///
+/// let mut meter = RmsMeter::new(4);
+/// meter.push_block(&[0.1, -0.1, 0.1, -0.1]);
+///
+/// assert!((meter.level_db() + 20.0).abs() < 1e-3);
+/// ```
+pub struct RmsMeter {
+    /// Squares of the most recent samples (ring buffer)
+    squares: Vec<f64>,
+    /// Write position in `squares`
+    position: usize,
+    /// Running sum of `squares`
+    sum_of_squares: f64,
+}
+
+impl RmsMeter {
+    /// Creates an RMS meter over a window of `window_len` samples.
+    ///
+    /// The window is allocated here, `push_block()` does not allocate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window_len` is 0.
+    pub fn new(window_len: usize) -> RmsMeter {
+        assert!(window_len > 0, "Window length must be greater than 0");
+        RmsMeter {
+            squares: vec![0.0; window_len],
+            position: 0,
+            sum_of_squares: 0.0,
+        }
+    }
+
+    /// Feeds a block of samples into the meter. Non-finite samples count as silence.
+    pub fn push_block(&mut self, buf: &[f32]) {
+        for &sample in buf {
+            let new = square(sample);
+            let old = std::mem::replace(&mut self.squares[self.position], new);
+            // clamp tiny negative values caused by rounding
+            self.sum_of_squares = (self.sum_of_squares + new - old).max(0.0);
+            self.position = (self.position + 1) % self.squares.len();
+        }
+    }
+
+    /// Returns the RMS level over the window in dB, -100.0 for silence.
+    pub fn level_db(&self) -> f32 {
+        let mean = self.sum_of_squares / self.squares.len() as f64;
+        level_to_db(mean.sqrt() as f32)
+    }
+
+    /// Resets the meter to silence.
+    pub fn reset(&mut self) {
+        self.squares.fill(0.0);
+        self.position = 0;
+        self.sum_of_squares = 0.0;
+    }
+}
+
+/// Standardised meter ballistics, i.e. how fast a `Meter` rises and falls.
+///
+/// The times are derived from the standards' integration and return times and converted into
+/// half-lives of the underlying `AttackReleaseSmoother`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ballistics {
+    /// VU meter: symmetric, reaches 99% of a step in 300 ms. Reads the average of the rectified
+    /// signal, i.e. about -3.9 dB for a full scale sine.
+    Vu,
+    /// Peak programme meter (DIN type): a 10 ms burst reads -1 dB, the level falls by 20 dB
+    /// in 1.5 s.
+    Ppm,
+    /// Digital peak meter: instant attack, falls by 20 dB in 1.5 s like `Ppm`.
+    DigitalPeak,
+}
+
+impl Ballistics {
+    /// Returns the attack and release half-lives in milliseconds.
+    fn half_lives_ms(self) -> (f32, f32) {
+        // fall by 20 dB (a factor of 10) in 1.5 s
+        let release_ms = 1500.0 / 10.0_f32.log2();
+        match self {
+            // 1% remaining after 300 ms
+            Ballistics::Vu => {
+                let half_life_ms = 300.0 / 100.0_f32.log2();
+                (half_life_ms, half_life_ms)
+            }
+            // -1 dB (89.1%) after 10 ms, i.e. 10.9% remaining
+            Ballistics::Ppm => (10.0 / (1.0 / (1.0 - 0.891_f32)).log2(), release_ms),
+            Ballistics::DigitalPeak => (0.0, release_ms),
+        }
+    }
+}
+
+/// A level meter with standardised ballistics, see `Ballistics`.
+///
+/// The rectified signal is smoothed per sample by an `AttackReleaseSmoother`, so rising and
+/// falling levels follow the attack and release times of the chosen standard.
+///
+/// # Example
+/// ```
+/// use audio_utils::{Ballistics, Meter};
+///
+/// let mut meter = Meter::new(48_000.0, Ballistics::DigitalPeak);
+/// meter.push_block(&[0.25, -0.5, 1.0]);
+///
+/// assert_eq!(meter.read_db(), 0.0);
+/// ```
+pub struct Meter {
+    smoother: AttackReleaseSmoother,
+    ballistics: Ballistics,
+    /// Current linear level
+    level: f32,
+}
+
+impl Meter {
+    /// Creates a meter at silence.
+    ///
+    /// # Parameters
+    /// * `sample_rate` - Sample rate in Hz
+    /// * `ballistics` - The standard defining attack and release times
+    pub fn new(sample_rate: f32, ballistics: Ballistics) -> Meter {
+        let (attack_ms, release_ms) = ballistics.half_lives_ms();
+        Meter {
+            smoother: AttackReleaseSmoother::from_times(sample_rate, attack_ms, release_ms, 0.0),
+            ballistics,
+            level: 0.0,
+        }
+    }
+
+    /// Feeds a block of samples into the meter. Non-finite samples are ignored.
+    pub fn push_block(&mut self, buf: &[f32]) {
+        for &sample in buf {
+            self.level = self.smoother.next(sample.abs());
+        }
+    }
+
+    /// Returns the current level in dB, -100.0 for silence.
+    pub fn read_db(&self) -> f32 {
+        level_to_db(self.level)
+    }
+
+    /// Returns the ballistics the meter was created with.
+    pub fn ballistics(&self) -> Ballistics {
+        self.ballistics
+    }
+
+    /// Resets the meter to silence.
+    pub fn reset(&mut self) {
+        self.smoother.reset();
+        self.level = 0.0;
+    }
+}
+
+/// A latching clip indicator, like the "clip" LED of a channel strip.
+///
+/// The indicator turns on when a sample reaches the ceiling (0 dBFS by default) and stays
+/// on until `clear()`, or until no sample has reached the ceiling for the hold time.
+///
+/// # Example
+/// ```
+/// use audio_utils::ClipIndicator;
+///
+/// let mut clip = ClipIndicator::default();
+/// clip.push_block(&[0.5, -1.2, 0.3]);
+/// assert!(clip.clipped());
+///
+/// clip.clear();
+/// assert!(!clip.clipped());
+/// ```
+pub struct ClipIndicator {
+    /// Linear level at or above which a sample counts as clipping
+    ceiling: f32,
+    /// Samples to stay on after the last clipping sample, `None` to latch until `clear()`
+    hold_samples: Option<u32>,
+    /// Samples left until the indicator turns off
+    remaining: u32,
+    clipped: bool,
+}
+
+impl Default for ClipIndicator {
+    /// Creates an indicator with a ceiling of 0 dBFS (|sample| >= 1.0) that latches until
+    /// `clear()`.
+    fn default() -> ClipIndicator {
+        ClipIndicator::new(0.0, None)
+    }
+}
+
+impl ClipIndicator {
+    /// Creates a clip indicator.
+    ///
+    /// # Parameters
+    /// * `ceiling_db` - Level in dBFS at or above which a sample counts as clipping,
+    ///   converted via `db_to_volt_f()`
+    /// * `hold_samples` - Number of samples the indicator stays on after the last clipping
+    ///   sample, or `None` to stay on until `clear()`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ceiling_db` is not finite.
+    pub fn new(ceiling_db: f32, hold_samples: Option<u32>) -> ClipIndicator {
+        assert!(
+            ceiling_db.is_finite(),
+            "Ceiling must be finite, got {}",
+            ceiling_db
+        );
+        ClipIndicator {
+            ceiling: db_to_volt_f(ceiling_db),
+            hold_samples,
+            remaining: 0,
+            clipped: false,
+        }
+    }
+
+    /// Feeds a block of samples into the indicator. Infinite samples count as clipping,
+    /// `NaN` samples are ignored.
+    ///
+    /// # Real-time safety
+    ///
+    /// Never panics and never allocates.
+    pub fn push_block(&mut self, buf: &[f32]) {
+        for &sample in buf {
+            if sample.abs() >= self.ceiling {
+                self.clipped = true;
+                self.remaining = self.hold_samples.unwrap_or(0);
+            } else if self.clipped && self.hold_samples.is_some() {
+                // stays on for `hold_samples` samples, turns off on the one after
+                if self.remaining == 0 {
+                    self.clipped = false;
+                } else {
+                    self.remaining -= 1;
+                }
+            }
+        }
+    }
+
+    /// Returns `true` while the indicator is on.
+    pub fn clipped(&self) -> bool {
+        self.clipped
+    }
+
+    /// Turns the indicator off, e.g. when the user clicks it.
+    pub fn clear(&mut self) {
+        self.clipped = false;
+        self.remaining = 0;
+    }
+}
+
+/// Rounds a slowly moving gain to integer dB for display, with error diffusion.
+///
+/// Plain rounding makes a readout stick at one value and then jump. `DbDisplayDither` carries
+/// the rounding error over to the next call, so the readout alternates between the two
+/// neighbouring integers and its long-run average matches the true level.
+///
+/// # Example
+/// ```
+/// use audio_utils::{db_to_volt_f, DbDisplayDither};
+///
+/// let mut dither = DbDisplayDither::new();
+/// let gain = db_to_volt_f(-6.5);
+///
+/// let readouts: Vec<i32> = (0..4).map(|_| dither.next(gain)).collect();
+/// assert_eq!(readouts.iter().sum::<i32>(), -26);
+/// ```
+#[derive(Debug, Default)]
+pub struct DbDisplayDither {
+    /// Rounding error carried over to the next readout, in dB
+    error: f32,
+}
+
+impl DbDisplayDither {
+    /// Creates a dither without carried error.
+    pub fn new() -> DbDisplayDither {
+        DbDisplayDither::default()
+    }
+
+    /// Returns the integer dB readout for `gain`, in the range `[-100, 27]`.
+    ///
+    /// The level is computed via `volt_to_db_f()`. Silence and `NaN` read -100 dB and don't
+    /// affect the carried error.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self, gain: f32) -> i32 {
+        let db = volt_to_db_f(gain);
+        if !db.is_finite() {
+            return DB_VOLT_LOOKUP_MIN;
+        }
+        let wanted = db + self.error;
+        let shown = wanted.round().clamp(DB_VOLT_LOOKUP_MIN as f32, DB_VOLT_LOOKUP_MAX as f32);
+        self.error = (wanted - shown).clamp(-0.5, 0.5);
+        shown as i32
+    }
+
+    /// Drops the carried error.
+    pub fn reset(&mut self) {
+        self.error = 0.0;
+    }
+}
+
+//--- Tests ---------------------------------------------------------------------------------------
+//
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::TAU;
+
+    fn sine(amplitude: f32, len: usize) -> Vec<f32> {
+        (0..len)
+            .map(|n| amplitude * (TAU * 1000.0 * n as f32 / 48_000.0).sin())
+            .collect()
+    }
+
+    //--- peak_db
+    #[test]
+    fn peak_db_of_full_scale_sine_is_zero() {
+        let block = sine(1.0, 480);
+        assert!(peak_db(&block).abs() < 0.01);
+    }
+
+    #[test]
+    fn peak_db_of_silence_is_minimum() {
+        assert_eq!(peak_db(&[0.0; 480]), -100.0);
+        assert_eq!(peak_db(&[]), -100.0);
+    }
+
+    #[test]
+    fn peak_db_ignores_non_finite_samples() {
+        assert_eq!(peak_db(&[0.1, f32::NAN, f32::INFINITY]), -20.0);
+    }
+
+    //--- rms_db
+    #[test]
+    fn rms_db_of_sine_is_3_db_below_peak() {
+        // -6 dBFS sine, 10 full periods
+        let block = sine(0.5, 480);
+        assert!((peak_db(&block) + 6.02).abs() < 0.01);
+        assert!((rms_db(&block) + 9.03).abs() < 0.05);
+    }
+
+    #[test]
+    fn rms_db_of_silence_is_minimum() {
+        assert_eq!(rms_db(&[0.0; 480]), -100.0);
+        assert_eq!(rms_db(&[]), -100.0);
+    }
+
+    //--- PeakMeter
+    #[test]
+    fn peak_meter_reports_full_scale_sine() {
+        let mut meter = PeakMeter::new(48_000.0, 300.0);
+        meter.push_block(&sine(1.0, 480));
+        assert!(meter.level_db().abs() < 0.1);
+    }
+
+    #[test]
+    fn peak_meter_reports_silence() {
+        let mut meter = PeakMeter::default();
+        meter.push_block(&[0.0; 480]);
+        assert_eq!(meter.level_db(), -100.0);
+    }
+
+    #[test]
+    fn peak_meter_decays_after_signal_stops() {
+        let mut meter = PeakMeter::new(48_000.0, 10.0);
+        meter.push_block(&[1.0]);
+        assert_eq!(meter.level_db(), 0.0);
+
+        // one half-life later, the level has dropped by ~6 dB
+        meter.push_block(&[0.0; 480]);
+        assert!((meter.level_db() + 6.02).abs() < 0.1);
+
+        meter.push_block(&[0.0; 48_000]);
+        assert_eq!(meter.level_db(), -100.0);
+    }
+
+    //--- StereoPeakMeter
+    #[test]
+    fn stereo_peak_meter_reads_channels_independently() {
+        let mut meter = StereoPeakMeter::new(48_000.0, 300.0);
+        let left = sine(0.5, 480);
+        for &l in &left {
+            meter.push_frame(l, 0.0);
+        }
+        assert!((meter.left_db() + 6.02).abs() < 0.1);
+        assert_eq!(meter.right_db(), -100.0);
+        assert_eq!(meter.linked_db(), meter.left_db());
+    }
+
+    #[test]
+    fn stereo_peak_meter_linked_follows_the_louder_channel() {
+        let mut meter = StereoPeakMeter::new(48_000.0, 10.0);
+        meter.push_frame(1.0, 0.1);
+        assert_eq!(meter.linked_db(), 0.0);
+
+        // left decays from 0 dB, right holds at -20 dB: linked switches to the right
+        for _ in 0..48_000 {
+            meter.push_frame(0.0, 0.1);
+        }
+        assert_eq!(meter.left_db(), -100.0);
+        assert_eq!(meter.right_db(), -20.0);
+        assert_eq!(meter.linked_db(), -20.0);
+    }
+
+    #[test]
+    fn stereo_peak_meter_can_be_reset() {
+        let mut meter = StereoPeakMeter::default();
+        meter.push_frame(1.0, -1.0);
+        meter.reset();
+        assert_eq!(meter.linked_db(), -100.0);
+    }
+
+    //--- RmsMeter
+    #[test]
+    fn rms_meter_reports_sine_3_db_below_peak() {
+        let mut meter = RmsMeter::new(480);
+        meter.push_block(&sine(0.5, 4800));
+        assert!((meter.level_db() + 9.03).abs() < 0.05);
+    }
+
+    #[test]
+    fn rms_meter_window_slides() {
+        let mut meter = RmsMeter::new(480);
+        meter.push_block(&sine(1.0, 4800));
+        meter.push_block(&[0.0; 479]);
+        assert!(meter.level_db() > -100.0);
+
+        // the whole window is silent now
+        meter.push_block(&[0.0]);
+        assert_eq!(meter.level_db(), -100.0);
+    }
+
+    #[test]
+    fn rms_meter_reports_silence() {
+        let mut meter = RmsMeter::new(480);
+        assert_eq!(meter.level_db(), -100.0);
+        meter.push_block(&[0.0; 1000]);
+        assert_eq!(meter.level_db(), -100.0);
+    }
+
+    #[test]
+    fn rms_meter_can_be_reset() {
+        let mut meter = RmsMeter::new(16);
+        meter.push_block(&[1.0; 16]);
+        meter.reset();
+        assert_eq!(meter.level_db(), -100.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Window length must be greater than 0")]
+    fn rms_meter_panics_on_empty_window() {
+        let _meter = RmsMeter::new(0);
+    }
+
+    #[test]
+    fn peak_meter_can_be_reset() {
+        let mut meter = PeakMeter::default();
+        meter.push_block(&[1.0]);
+        meter.reset();
+        assert_eq!(meter.level_db(), -100.0);
+    }
+
+    //--- Meter
+    #[test]
+    fn meter_settles_on_steady_levels() {
+        // a square wave rectifies to a constant level
+        let square: Vec<f32> = (0..96_000).map(|n| if n % 48 < 24 { 0.5 } else { -0.5 }).collect();
+        for ballistics in [Ballistics::Vu, Ballistics::Ppm, Ballistics::DigitalPeak] {
+            let mut meter = Meter::new(48_000.0, ballistics);
+            meter.push_block(&square);
+            assert!((meter.read_db() + 6.02).abs() < 0.01, "{ballistics:?}");
+        }
+    }
+
+    #[test]
+    fn vu_meter_reads_average_of_rectified_sine() {
+        let mut meter = Meter::new(48_000.0, Ballistics::Vu);
+        meter.push_block(&sine(1.0, 96_000));
+        // 20 * log10(2 / pi), with some ripple of the 1 kHz tone
+        assert!((meter.read_db() + 3.92).abs() < 0.1, "{}", meter.read_db());
+    }
+
+    #[test]
+    fn ppm_attack_is_faster_than_release() {
+        let mut meter = Meter::new(48_000.0, Ballistics::Ppm);
+
+        // a 10 ms burst reads -1 dB
+        meter.push_block(&[1.0; 480]);
+        assert!((meter.read_db() + 1.0).abs() < 0.05, "{}", meter.read_db());
+
+        // but 10 ms of silence hardly lowers the level
+        meter.push_block(&[1.0; 48_000]);
+        meter.push_block(&[0.0; 480]);
+        assert!(meter.read_db() > -0.2, "{}", meter.read_db());
+
+        // -20 dB after 1.5 s
+        meter.push_block(&[0.0; 72_000 - 480]);
+        assert!((meter.read_db() + 20.0).abs() < 0.05, "{}", meter.read_db());
+    }
+
+    #[test]
+    fn vu_meter_is_symmetric() {
+        let mut meter = Meter::new(48_000.0, Ballistics::Vu);
+        meter.push_block(&[1.0; 14_400]);
+        let rise = meter.read_db();
+        meter.push_block(&[1.0; 48_000]);
+        meter.push_block(&[0.0; 14_400]);
+        // 99% of the step in 300 ms, in both directions
+        assert!((rise - volt_to_db_f(0.99)).abs() < 0.01);
+        assert!((meter.read_db() - volt_to_db_f(0.01)).abs() < 0.05);
+    }
+
+    #[test]
+    fn meter_ignores_non_finite_samples_and_resets() {
+        let mut meter = Meter::new(48_000.0, Ballistics::DigitalPeak);
+        meter.push_block(&[0.5, f32::NAN, f32::INFINITY]);
+        assert!((meter.read_db() + 6.02).abs() < 0.01);
+        meter.reset();
+        assert_eq!(meter.read_db(), -100.0);
+        assert_eq!(meter.ballistics(), Ballistics::DigitalPeak);
+    }
+
+    //--- DbDisplayDither
+    #[test]
+    fn db_display_dither_averages_to_true_level() {
+        use crate::decibels::db_to_volt_f;
+
+        for db in [-6.3, -0.75, 12.1, -99.6] {
+            let mut dither = DbDisplayDither::new();
+            let gain = db_to_volt_f(db);
+            let true_db = volt_to_db_f(gain);
+            let readouts: Vec<i32> = (0..10_000).map(|_| dither.next(gain)).collect();
+
+            // only the two neighbouring integers are shown
+            let neighbours = [true_db.floor() as i32, true_db.ceil() as i32];
+            assert!(readouts.iter().all(|readout| neighbours.contains(readout)));
+            let average = readouts.iter().sum::<i32>() as f32 / readouts.len() as f32;
+            assert!((average - true_db).abs() < 0.1, "db = {db}, average = {average}");
+        }
+    }
+
+    #[test]
+    fn db_display_dither_reads_silence_as_minimum() {
+        let mut dither = DbDisplayDither::new();
+        assert_eq!(dither.next(0.0), -100);
+        assert_eq!(dither.next(f32::NAN), -100);
+        assert_eq!(dither.next(100.0), 27);
+        dither.reset();
+        assert_eq!(dither.next(1.0), 0);
+    }
+
+    //--- ClipIndicator
+    #[test]
+    fn clip_indicator_latches_on_overage() {
+        let mut clip = ClipIndicator::default();
+        clip.push_block(&[0.5, -0.99, f32::NAN]);
+        assert!(!clip.clipped());
+
+        clip.push_block(&[0.1, -1.0, 0.1]);
+        assert!(clip.clipped());
+        // stays on without a hold time
+        clip.push_block(&[0.0; 48_000]);
+        assert!(clip.clipped());
+
+        clip.clear();
+        assert!(!clip.clipped());
+        clip.push_block(&[f32::INFINITY]);
+        assert!(clip.clipped());
+    }
+
+    #[test]
+    fn clip_indicator_uses_the_ceiling() {
+        let mut clip = ClipIndicator::new(-6.0, None);
+        clip.push_block(&[0.49]);
+        assert!(!clip.clipped());
+        clip.push_block(&[0.51]);
+        assert!(clip.clipped());
+    }
+
+    #[test]
+    fn clip_indicator_clears_after_the_hold_time() {
+        let mut clip = ClipIndicator::new(0.0, Some(4));
+        clip.push_block(&[1.5]);
+        for n in 1..=4 {
+            clip.push_block(&[0.0]);
+            assert!(clip.clipped(), "{n} samples after clipping");
+        }
+        clip.push_block(&[0.0]);
+        assert!(!clip.clipped());
+
+        // a new overage restarts the hold time
+        clip.push_block(&[1.5, 0.0, 0.0, 0.0, 1.5, 0.0, 0.0, 0.0, 0.0]);
+        assert!(clip.clipped());
+        clip.push_block(&[0.0]);
+        assert!(!clip.clipped());
+    }
+
+    #[test]
+    fn clip_indicator_hold_counts_samples_after_the_clip() {
+        let mut clip = ClipIndicator::new(0.0, Some(0));
+        clip.push_block(&[1.5, 0.0]);
+        assert!(!clip.clipped());
+
+        let mut clip = ClipIndicator::new(0.0, Some(1));
+        clip.push_block(&[1.5, 0.0]);
+        assert!(clip.clipped());
+        clip.push_block(&[0.0]);
+        assert!(!clip.clipped());
+    }
+
+    #[test]
+    #[should_panic(expected = "Ceiling must be finite, got NaN")]
+    fn clip_indicator_rejects_nan_ceiling() {
+        ClipIndicator::new(f32::NAN, None);
+    }
+}
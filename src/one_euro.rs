@@ -0,0 +1,194 @@
+//! The One Euro filter: jitter-free yet responsive smoothing for noisy controls.
+//!
+//! A fixed-beta smoother either lags behind fast movements or lets jitter through. The One
+//! Euro filter (Casiez, Roussel, Vogel; CHI 2012) adapts its cutoff frequency to the speed
+//! of the signal: slow movements get a low cutoff (little jitter), fast movements a high
+//! cutoff (little lag). It consists of two one-pole filters, both `TinySmoother`s here:
+//! one smooths the derivative, the other the value with the adaptive cutoff.
+
+use crate::smoother::Smoother;
+use crate::tiny_smoother::{beta_from_cutoff, TinySmoother};
+
+/// One Euro filter for noisy control signals (sensors, MIDI CC).
+///
+/// # Example
+/// ```
+/// use audio_utils::OneEuroSmoother;
+///
+/// // control rate of 1 kHz, 1 Hz minimum cutoff
+/// let mut smoother = OneEuroSmoother::new(1000.0, 1.0, 0.5, 1.0);
+///
+/// let value = smoother.next(0.5);
+/// assert_eq!(value, 0.5); // the first value passes through
+/// ```
+pub struct OneEuroSmoother {
+    /// Smooths the value, its coefficient adapts to the speed of the signal
+    value: TinySmoother,
+    /// Smooths the derivative with the fixed derivative cutoff
+    derivative: TinySmoother,
+    sample_rate: f64,
+    min_cutoff: f64,
+    beta_coeff: f64,
+    /// `false` until the first value has been processed
+    initialized: bool,
+}
+
+impl OneEuroSmoother {
+    /// Creates a One Euro filter.
+    ///
+    /// # Parameters
+    /// * `sample_rate` - Rate in Hz at which `next()` is called
+    /// * `min_cutoff` - Cutoff frequency in Hz while the signal is at rest; lower values
+    ///   reject more jitter
+    /// * `beta_coeff` - How fast the cutoff rises with the speed of the signal; higher values
+    ///   reduce lag on fast movements
+    /// * `d_cutoff` - Cutoff frequency in Hz for smoothing the derivative, usually 1.0
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sample_rate` is not positive or if a cutoff or `beta_coeff` is negative.
+    pub fn new(
+        sample_rate: f32,
+        min_cutoff: f32,
+        beta_coeff: f32,
+        d_cutoff: f32,
+    ) -> OneEuroSmoother {
+        assert!(
+            sample_rate.is_finite() && sample_rate > 0.0,
+            "Sample rate must be finite and positive, got {}",
+            sample_rate
+        );
+        assert!(
+            min_cutoff >= 0.0 && d_cutoff >= 0.0,
+            "Cutoff frequencies must be non-negative, got {} and {}",
+            min_cutoff,
+            d_cutoff
+        );
+        assert!(
+            beta_coeff >= 0.0,
+            "Beta coefficient must be non-negative, got {}",
+            beta_coeff
+        );
+        let sample_rate = sample_rate as f64;
+        OneEuroSmoother {
+            value: TinySmoother::new(beta_from_cutoff(sample_rate, min_cutoff as f64), 0.0),
+            derivative: TinySmoother::new(beta_from_cutoff(sample_rate, d_cutoff as f64), 0.0),
+            sample_rate,
+            min_cutoff: min_cutoff as f64,
+            beta_coeff: beta_coeff as f64,
+            initialized: false,
+        }
+    }
+
+    /// Processes the next (noisy) value and returns the filtered value.
+    ///
+    /// The first value after creation or `reset()` passes through unfiltered.
+    /// Non-finite values are ignored and return the current value.
+    pub fn next(&mut self, value: f32) -> f32 {
+        if !value.is_finite() {
+            return self.value.current_value_f64() as f32;
+        }
+        if !self.initialized {
+            self.initialized = true;
+            self.value.reset_to(value);
+            return value;
+        }
+        let speed = (value as f64 - self.value.current_value_f64()) * self.sample_rate;
+        let smoothed_speed = self.derivative.next_f64(speed);
+
+        let cutoff = self.min_cutoff + self.beta_coeff * smoothed_speed.abs();
+        self.value.set_beta(beta_from_cutoff(self.sample_rate, cutoff));
+        self.value.next(value)
+    }
+
+    /// Forgets the history; the next value passes through unfiltered.
+    pub fn reset(&mut self) {
+        self.value.reset();
+        self.derivative.reset();
+        self.initialized = false;
+    }
+}
+
+impl Smoother for OneEuroSmoother {
+    #[inline]
+    fn next(&mut self, value: f32) -> f32 {
+        OneEuroSmoother::next(self, value)
+    }
+
+    fn reset(&mut self) {
+        OneEuroSmoother::reset(self);
+    }
+
+    fn current_value(&self) -> f32 {
+        self.value.current_value_f64() as f32
+    }
+}
+
+//--- Tests ---------------------------------------------------------------------------------------
+//
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, SeedableRng, rngs::SmallRng};
+
+    const CONTROL_RATE: f32 = 1000.0;
+
+    fn one_euro() -> OneEuroSmoother {
+        OneEuroSmoother::new(CONTROL_RATE, 1.0, 0.5, 1.0)
+    }
+
+    #[test]
+    fn one_euro_tracks_fast_steps_quickly() {
+        let mut smoother = one_euro();
+        // a fixed one-pole with the same (minimum) cutoff for comparison
+        let mut one_pole = TinySmoother::new(beta_from_cutoff(CONTROL_RATE as f64, 1.0), 0.0);
+        smoother.next(0.0);
+
+        let samples_one_euro = (1..).find(|_| smoother.next(1.0) >= 0.9).unwrap();
+        let samples_one_pole = (1..).find(|_| one_pole.next(1.0) >= 0.9).unwrap();
+
+        assert!(samples_one_euro < 50, "samples = {samples_one_euro}");
+        assert!(samples_one_euro * 5 < samples_one_pole);
+    }
+
+    #[test]
+    fn one_euro_rejects_jitter_on_held_value() {
+        let mut smoother = one_euro();
+        let mut rng = SmallRng::seed_from_u64(0xDEC1_BA5E_u64);
+        let jitter = 0.01f32;
+
+        smoother.next(0.5);
+        let mut max_deviation = 0.0f32;
+        for i in 0..5000 {
+            let noisy = 0.5 + rng.random_range(-jitter..=jitter);
+            let value = smoother.next(noisy);
+            if i > 1000 {
+                max_deviation = max_deviation.max((value - 0.5).abs());
+            }
+        }
+        assert!(max_deviation < jitter / 5.0, "max deviation = {max_deviation}");
+    }
+
+    #[test]
+    fn one_euro_ignores_non_finite_values() {
+        let mut smoother = one_euro();
+        smoother.next(0.25);
+        assert_eq!(smoother.next(f32::NAN), 0.25);
+        assert_eq!(smoother.next(f32::INFINITY), 0.25);
+    }
+
+    #[test]
+    fn one_euro_reset_passes_next_value_through() {
+        let mut smoother = one_euro();
+        smoother.next(0.0);
+        smoother.next(1.0);
+        smoother.reset();
+        assert_eq!(smoother.next(0.75), 0.75);
+    }
+
+    #[test]
+    #[should_panic(expected = "Sample rate must be finite and positive")]
+    fn one_euro_panics_on_zero_sample_rate() {
+        let _smoother = OneEuroSmoother::new(0.0, 1.0, 0.5, 1.0);
+    }
+}
@@ -0,0 +1,203 @@
+//! Pan laws for distributing a mono signal across the stereo field.
+//!
+//! A pan law determines how much the level drops at the center position compared to a
+//! hard-panned signal. The equal-power law (-3 dB at center) keeps the perceived loudness
+//! constant across the stereo field.
+//!
+//! # Stereo vs. mono
+//!
+//! No law is level-consistent in both stereo and a mono sum (`left + right`): the two gains
+//! of a centered signal add up in amplitude when summed to mono, but in power when played
+//! back in stereo. Relative to a hard-panned signal, the center position measures:
+//!
+//! | Law           | Center gain | Stereo power | Mono sum |
+//! |---------------|-------------|--------------|----------|
+//! | `EqualPower`  | -3 dB       | 0 dB         | +3 dB    |
+//! | `Compromise`  | -4.5 dB     | -1.5 dB      | +1.5 dB  |
+//! | `ConstantSum` | -6 dB       | -3 dB        | 0 dB     |
+//! | `Linear`      | 0 dB        | +3 dB        | +6 dB    |
+//!
+//! Use `ConstantSum` when the mix is monitored or delivered in mono, `EqualPower` for stereo.
+//!
+//! # Examples
+//!
+//! ```
+//! use audio_utils::equal_power_pan;
+//!
+//! let (left, right) = equal_power_pan(0.0);
+//! assert_eq!(left, right);
+//! ```
+
+use std::f32::consts::FRAC_PI_2;
+
+/// The attenuation applied to both channels at the center position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PanLaw {
+    /// -3 dB at center, `left² + right² = 1` everywhere (sine/cosine law).
+    #[default]
+    EqualPower,
+    /// -4.5 dB at center, the geometric mean of the equal-power and the linear -6 dB law.
+    Compromise,
+    /// 0 dB at center: the opposite channel is attenuated linearly, like a balance control.
+    Linear,
+    /// -6 dB at center, `left + right = 1` everywhere: the mono sum keeps its level across
+    /// the stereo field, while the stereo power dips by 3 dB at center.
+    ConstantSum,
+}
+
+/// Returns the `(left, right)` gains for the given pan position using the equal-power law.
+///
+/// # Arguments
+///
+/// * `pan` - Pan position from -1.0 (hard left) over 0.0 (center) to 1.0 (hard right),
+///   clamped to this range. `NaN` pans to the center.
+///
+/// # Example
+/// ```
+/// use audio_utils::equal_power_pan;
+///
+/// let (left, right) = equal_power_pan(-1.0);
+///
+/// assert_eq!(left, 1.0);
+/// assert!(right.abs() < 1e-6);
+/// ```
+#[inline]
+pub fn equal_power_pan(pan: f32) -> (f32, f32) {
+    pan_gains(pan, PanLaw::EqualPower)
+}
+
+/// Returns the `(left, right)` gains for the given pan position using the given `law`.
+///
+/// Hard left yields `(1.0, 0.0)` and hard right yields `(0.0, 1.0)` for all laws.
+///
+/// # Arguments
+///
+/// * `pan` - Pan position from -1.0 (hard left) over 0.0 (center) to 1.0 (hard right),
+///   clamped to this range. `NaN` pans to the center.
+/// * `law` - The pan law, see [`PanLaw`].
+///
+/// # Example
+/// ```
+/// use audio_utils::{pan_gains, PanLaw};
+///
+/// let (left, right) = pan_gains(0.0, PanLaw::Linear);
+///
+/// assert_eq!((left, right), (1.0, 1.0));
+/// ```
+pub fn pan_gains(pan: f32, law: PanLaw) -> (f32, f32) {
+    let pan = if pan.is_nan() { 0.0 } else { pan.clamp(-1.0, 1.0) };
+    // position in [0.0, 1.0], 0.0 is hard left
+    let position = (pan + 1.0) * 0.5;
+
+    match law {
+        PanLaw::EqualPower => equal_power(position),
+        PanLaw::Compromise => {
+            let (left, right) = equal_power(position);
+            ((left * (1.0 - position)).sqrt(), (right * position).sqrt())
+        }
+        PanLaw::Linear => (
+            (2.0 * (1.0 - position)).min(1.0),
+            (2.0 * position).min(1.0),
+        ),
+        PanLaw::ConstantSum => (1.0 - position, position),
+    }
+}
+
+/// Quarter-circle gains for a position in [0.0, 1.0].
+#[inline]
+pub(crate) fn equal_power(position: f32) -> (f32, f32) {
+    let angle = position * FRAC_PI_2;
+    // `cos(π/2)` is slightly negative in f32
+    (angle.cos().max(0.0), angle.sin())
+}
+
+//--- Tests ---------------------------------------------------------------------------------------
+//
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decibels::volt_to_db_f;
+
+    const LAWS: [PanLaw; 4] = [
+        PanLaw::EqualPower,
+        PanLaw::Compromise,
+        PanLaw::Linear,
+        PanLaw::ConstantSum,
+    ];
+
+    #[test]
+    fn center_gives_equal_gains() {
+        for law in LAWS {
+            let (left, right) = pan_gains(0.0, law);
+            assert_eq!(left, right, "{law:?}");
+        }
+    }
+
+    #[test]
+    fn center_attenuation_matches_the_law() {
+        let (left, _) = pan_gains(0.0, PanLaw::EqualPower);
+        assert!((volt_to_db_f(left) + 3.01).abs() < 0.05);
+
+        let (left, _) = pan_gains(0.0, PanLaw::Compromise);
+        assert!((volt_to_db_f(left) + 4.5).abs() < 0.05);
+
+        let (left, _) = pan_gains(0.0, PanLaw::Linear);
+        assert_eq!(left, 1.0);
+
+        let (left, _) = pan_gains(0.0, PanLaw::ConstantSum);
+        assert_eq!(left, 0.5);
+    }
+
+    #[test]
+    fn stereo_power_and_mono_sum_at_center_match_the_table() {
+        // (law, stereo power in dB, mono sum in dB) relative to hard left, see module docs
+        let table = [
+            (PanLaw::EqualPower, 0.0, 3.01),
+            (PanLaw::Compromise, -1.5, 1.5),
+            (PanLaw::ConstantSum, -3.01, 0.0),
+            (PanLaw::Linear, 3.01, 6.02),
+        ];
+        for (law, power_db, mono_db) in table {
+            let (left, right) = pan_gains(0.0, law);
+            let power = volt_to_db_f((left * left + right * right).sqrt());
+            let mono = volt_to_db_f(left + right);
+            assert!((power - power_db).abs() < 0.05, "{law:?}: power {power} dB");
+            assert!((mono - mono_db).abs() < 0.05, "{law:?}: mono {mono} dB");
+        }
+    }
+
+    #[test]
+    fn constant_sum_keeps_the_mono_sum() {
+        for i in -100..=100 {
+            let (left, right) = pan_gains(i as f32 / 100.0, PanLaw::ConstantSum);
+            assert!((left + right - 1.0).abs() < 1e-6, "pan = {i}%");
+        }
+    }
+
+    #[test]
+    fn equal_power_conserves_power() {
+        for i in -100..=100 {
+            let (left, right) = equal_power_pan(i as f32 / 100.0);
+            let power = left * left + right * right;
+            assert!((power - 1.0).abs() < 1e-6, "pan = {i}%");
+        }
+    }
+
+    #[test]
+    fn extremes_are_hard_panned() {
+        for law in LAWS {
+            let (left, right) = pan_gains(-1.0, law);
+            assert!((left - 1.0).abs() < 1e-6 && right.abs() < 1e-6, "{law:?}");
+
+            let (left, right) = pan_gains(1.0, law);
+            assert!(left.abs() < 1e-6 && (right - 1.0).abs() < 1e-6, "{law:?}");
+        }
+    }
+
+    #[test]
+    fn pan_is_clamped_and_nan_is_centered() {
+        assert_eq!(equal_power_pan(-5.0), equal_power_pan(-1.0));
+        assert_eq!(equal_power_pan(5.0), equal_power_pan(1.0));
+        assert_eq!(equal_power_pan(f32::NAN), equal_power_pan(0.0));
+    }
+}
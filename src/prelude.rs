@@ -0,0 +1,29 @@
+//! The most commonly used items, for a single glob import.
+//!
+//! The prelude is kept small on purpose: the conversion and smoother traits, the core dB
+//! conversions and the few types nearly every plugin needs. Everything else is imported
+//! from the crate root as usual.
+//!
+//! # Examples
+//!
+//! ```
+//! use audio_utils::prelude::*;
+//!
+//! let gain = db_to_volt(-6);
+//! assert_eq!(volt_to_db(gain), -6);
+//!
+//! // the traits are in scope as well
+//! assert_eq!((-6).to_volt(), gain);
+//! assert_eq!(gain.to_db(), -6);
+//!
+//! let mut smoother = TinySmoother::default();
+//! let smoothed = smoother.next(Gain::from(Decibels(-6.0)).0);
+//! ```
+
+pub use crate::decibels::{
+    db_to_volt, db_to_volt_f, volt_to_db, volt_to_db_f, DbToVolt, ToDb, VoltToDb,
+};
+pub use crate::gain::{Decibels, Gain};
+pub use crate::smoothed_gain::SmoothedGain;
+pub use crate::smoother::Smoother;
+pub use crate::tiny_smoother::TinySmoother;
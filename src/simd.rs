@@ -0,0 +1,130 @@
+//! Explicitly vectorised kernels, enabled with the `simd` feature.
+//!
+//! The kernels are selected at runtime via CPU feature detection. Each kernel processes
+//! as many elements as fit into full vectors and returns that count, the caller finishes
+//! the remainder with the scalar code path. This keeps the results identical to the
+//! scalar path and the scalar path is the fallback on all other CPUs.
+
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+/// Converts dB values to voltage ratios with AVX2 gathers from `table`, 8 values per
+/// iteration. Returns the number of converted values, the remainder is left untouched.
+///
+/// `min_db` and `max_db` are the dB values of the first and last table entry.
+#[cfg(target_arch = "x86_64")]
+pub(crate) fn db_to_volt_lookup(
+    table: &[f32],
+    min_db: i32,
+    max_db: i32,
+    dbs: &[i32],
+    out: &mut [f32],
+) -> usize {
+    assert_eq!(table.len() as i32, max_db - min_db + 1);
+    if is_x86_feature_detected!("avx2") {
+        // SAFETY: AVX2 support was detected above.
+        unsafe { db_to_volt_lookup_avx2(table, min_db, max_db, dbs, out) }
+    } else {
+        0
+    }
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+pub(crate) fn db_to_volt_lookup(
+    _table: &[f32],
+    _min_db: i32,
+    _max_db: i32,
+    _dbs: &[i32],
+    _out: &mut [f32],
+) -> usize {
+    0
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+fn db_to_volt_lookup_avx2(
+    table: &[f32],
+    min_db: i32,
+    max_db: i32,
+    dbs: &[i32],
+    out: &mut [f32],
+) -> usize {
+    const LANES: usize = 8;
+    let min = _mm256_set1_epi32(min_db);
+    let max = _mm256_set1_epi32(max_db);
+
+    let mut chunks_out = out.chunks_exact_mut(LANES);
+    for (db_chunk, out_chunk) in dbs.chunks_exact(LANES).zip(&mut chunks_out) {
+        // SAFETY: both chunks hold exactly 8 elements, the indices are clamped to the table.
+        unsafe {
+            let db = _mm256_loadu_si256(db_chunk.as_ptr().cast());
+            let db = _mm256_min_epi32(_mm256_max_epi32(db, min), max);
+            let idx = _mm256_sub_epi32(db, min);
+            let volt = _mm256_i32gather_ps::<4>(table.as_ptr(), idx);
+            _mm256_storeu_ps(out_chunk.as_mut_ptr(), volt);
+        }
+    }
+    dbs.len().min(out.len()) / LANES * LANES
+}
+
+/// Advances four one-pole smoothers with a shared `beta` by one step, two lanes per SSE2
+/// vector in f64: `last = target - beta * (target - last)`, the same recurrence as
+/// `TinySmoother`.
+#[cfg(target_arch = "x86_64")]
+#[inline]
+pub(crate) fn one_pole_step4(last: &mut [f64; 4], targets: &[f64; 4], beta: f64) {
+    // SAFETY: SSE2 is part of the x86_64 baseline, all loads and stores are unaligned and
+    // stay within the 4-element arrays.
+    unsafe {
+        let beta = _mm_set1_pd(beta);
+        for half in 0..2 {
+            let last_ptr = last.as_mut_ptr().add(2 * half);
+            let target = _mm_loadu_pd(targets.as_ptr().add(2 * half));
+            let previous = _mm_loadu_pd(last_ptr);
+            let new = _mm_sub_pd(target, _mm_mul_pd(beta, _mm_sub_pd(target, previous)));
+            _mm_storeu_pd(last_ptr, new);
+        }
+    }
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+#[inline]
+pub(crate) fn one_pole_step4(last: &mut [f64; 4], targets: &[f64; 4], beta: f64) {
+    for (last, &target) in last.iter_mut().zip(targets) {
+        *last = target - beta * (target - *last);
+    }
+}
+
+/// Multiplies `buf` by `gain` with AVX, 8 samples per iteration. Returns the number of
+/// processed samples, the remainder is left untouched.
+#[cfg(target_arch = "x86_64")]
+pub(crate) fn scale(buf: &mut [f32], gain: f32) -> usize {
+    if is_x86_feature_detected!("avx") {
+        // SAFETY: AVX support was detected above.
+        unsafe { scale_avx(buf, gain) }
+    } else {
+        0
+    }
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+pub(crate) fn scale(_buf: &mut [f32], _gain: f32) -> usize {
+    0
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx")]
+fn scale_avx(buf: &mut [f32], gain: f32) -> usize {
+    const LANES: usize = 8;
+    let gain = _mm256_set1_ps(gain);
+
+    let mut chunks = buf.chunks_exact_mut(LANES);
+    for chunk in &mut chunks {
+        // SAFETY: the chunk holds exactly 8 elements.
+        unsafe {
+            let samples = _mm256_loadu_ps(chunk.as_ptr());
+            _mm256_storeu_ps(chunk.as_mut_ptr(), _mm256_mul_ps(samples, gain));
+        }
+    }
+    buf.len() / LANES * LANES
+}
@@ -0,0 +1,142 @@
+//! A gain parameter in dB, smoothed in the linear domain.
+//!
+//! Plugins typically expose gain in dB, but have to apply a linear factor per sample
+//! without zipper noise. `SmoothedGain` combines the dB→gain conversion and a
+//! `TinySmoother`, so a `process` loop only needs `set_db()` and `next_gain()`.
+//!
+//! # Examples
+//!
+//! ```
+//! use audio_utils::SmoothedGain;
+//!
+//! let mut gain = SmoothedGain::new(48_000.0, 10.0);
+//! let mut buffer = [[0.5f32; 2]; 64];
+//!
+//! // once per block, from the plugin's dB parameter
+//! gain.set_db(-6.0);
+//!
+//! for frame in buffer.iter_mut() {
+//!     let g = gain.next_gain();
+//!     for sample in frame.iter_mut() {
+//!         *sample *= g;
+//!     }
+//! }
+//! ```
+
+use crate::tiny_smoother::{beta_from_half_life, ms_to_samples, TinySmoother};
+
+/// A linear gain smoothed toward a target set in dB, starting at unity gain (0 dB).
+pub struct SmoothedGain {
+    smoother: TinySmoother,
+}
+
+impl Default for SmoothedGain {
+    /// Creates a gain at 0 dB with the smoothing time of `TinySmoother::default()`.
+    fn default() -> SmoothedGain {
+        let beta = beta_from_half_life(500.0);
+        SmoothedGain {
+            smoother: TinySmoother::new(beta, 1.0),
+        }
+    }
+}
+
+impl SmoothedGain {
+    /// Creates a gain at 0 dB.
+    ///
+    /// # Parameters
+    /// * `sample_rate` - Sample rate in Hz
+    /// * `half_life_ms` - Time in milliseconds after which a gain change is halfway done
+    ///
+    /// # Panics
+    ///
+    /// Panics if `half_life_ms` is negative.
+    pub fn new(sample_rate: f32, half_life_ms: f32) -> SmoothedGain {
+        let beta = beta_from_half_life(ms_to_samples(sample_rate, half_life_ms));
+        SmoothedGain {
+            smoother: TinySmoother::new(beta, 1.0),
+        }
+    }
+
+    /// Sets the target level in dB, converted via `db_to_volt_f()`.
+    ///
+    /// `f32::NEG_INFINITY` fades to silence, `NaN` and `f32::INFINITY` are ignored.
+    pub fn set_db(&mut self, db: f32) {
+        self.smoother.set_target_db(db);
+    }
+
+    /// Returns the smoothed linear gain for the next sample.
+    ///
+    /// # Real-time safety
+    ///
+    /// Never panics and never allocates in release builds.
+    #[inline]
+    pub fn next_gain(&mut self) -> f32 {
+        self.smoother.tick()
+    }
+
+    /// Returns `true` while the gain is still moving toward the target.
+    pub fn is_smoothing(&self) -> bool {
+        self.smoother.is_smoothing()
+    }
+
+    /// Jumps back to unity gain (0 dB) without smoothing, e.g. from a plugin's `reset()`.
+    pub fn reset(&mut self) {
+        self.smoother.reset();
+    }
+}
+
+//--- Tests ---------------------------------------------------------------------------------------
+//
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decibels::db_to_volt;
+
+    #[test]
+    fn starts_at_unity_gain() {
+        let mut gain = SmoothedGain::new(48_000.0, 10.0);
+        assert_eq!(gain.next_gain(), 1.0);
+        assert!(!gain.is_smoothing());
+    }
+
+    #[test]
+    fn db_change_ramps_linear_gain_smoothly() {
+        let mut gain = SmoothedGain::new(48_000.0, 1.0);
+        gain.set_db(-20.0);
+
+        let mut last = 1.0;
+        for _ in 0..48 {
+            let value = gain.next_gain();
+            assert!(value < last, "gain must fall monotonically");
+            assert!(last - value < 0.02, "gain must not jump");
+            last = value;
+        }
+        // one half-life: halfway between 1.0 and 0.1
+        assert!((last - 0.55).abs() < 1e-3);
+
+        for _ in 0..48_000 {
+            gain.next_gain();
+        }
+        assert_eq!(gain.next_gain(), db_to_volt(-20));
+    }
+
+    #[test]
+    fn ignores_invalid_levels() {
+        let mut gain = SmoothedGain::default();
+        gain.set_db(f32::NAN);
+        gain.set_db(f32::INFINITY);
+        assert_eq!(gain.next_gain(), 1.0);
+
+        gain.set_db(f32::NEG_INFINITY);
+        assert!(gain.next_gain() < 1.0);
+    }
+
+    #[test]
+    fn reset_returns_to_unity_gain() {
+        let mut gain = SmoothedGain::default();
+        gain.set_db(-12.0);
+        gain.next_gain();
+        gain.reset();
+        assert_eq!(gain.next_gain(), 1.0);
+    }
+}
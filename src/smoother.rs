@@ -0,0 +1,95 @@
+//! A common interface for the smoothers, for generic DSP code.
+//!
+//! `TinySmoother`, `TinySmootherF32`, `Cascade2Smoother`, `AttackReleaseSmoother`,
+//! `OneEuroSmoother` and `Log2Smoother` all follow a target value sample by sample. Code
+//! that only needs that behaviour can accept any of them through the `Smoother` trait.
+//!
+//! # Examples
+//!
+//! ```
+//! use audio_utils::{AttackReleaseSmoother, Smoother, TinySmoother};
+//!
+//! fn apply_gain<S: Smoother>(gain: &mut S, target: f32, buffer: &mut [f32]) {
+//!     for sample in buffer.iter_mut() {
+//!         *sample *= gain.next(target);
+//!     }
+//! }
+//!
+//! let mut buffer = [0.5f32; 64];
+//! apply_gain(&mut TinySmoother::default(), 1.0, &mut buffer);
+//! apply_gain(&mut AttackReleaseSmoother::from_times(48_000.0, 1.0, 50.0, 1.0), 0.5, &mut buffer);
+//! ```
+
+/// A filter that follows a target value sample by sample.
+///
+/// The implementing types have inherent methods of the same names, so calls on a concrete
+/// type don't need this trait in scope.
+pub trait Smoother {
+    /// Processes the next target value and returns the smoothed value. Non-finite targets
+    /// are ignored and return the current value.
+    fn next(&mut self, target: f32) -> f32;
+
+    /// Resets the smoother to its starting state.
+    fn reset(&mut self);
+
+    /// Returns the current (most recently produced) value.
+    fn current_value(&self) -> f32;
+}
+
+//--- Tests ---------------------------------------------------------------------------------------
+//
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        AttackReleaseSmoother, Cascade2Smoother, Log2Smoother, OneEuroSmoother, TinySmoother,
+        TinySmootherF32,
+    };
+
+    /// Generic code under test: smooths a step and returns the last value.
+    fn run_step<S: Smoother>(smoother: &mut S, target: f32, samples: usize) -> f32 {
+        for _ in 0..samples {
+            smoother.next(target);
+        }
+        smoother.current_value()
+    }
+
+    #[test]
+    fn generic_code_drives_different_smoothers() {
+        let mut tiny = TinySmoother::new(0.5, 0.0);
+        let mut attack_release = AttackReleaseSmoother::new(0.5, 0.9, 0.0);
+        assert_eq!(run_step(&mut tiny, 1.0, 2), 0.75);
+        assert_eq!(run_step(&mut attack_release, 1.0, 2), 0.75);
+
+        // only the release coefficient differs
+        assert_eq!(run_step(&mut tiny, 0.0, 1), 0.375);
+        assert!((run_step(&mut attack_release, 0.0, 1) - 0.675).abs() < 1e-6);
+
+        Smoother::reset(&mut tiny);
+        Smoother::reset(&mut attack_release);
+        assert_eq!(Smoother::current_value(&tiny), 0.0);
+        assert_eq!(Smoother::current_value(&attack_release), 0.0);
+    }
+
+    #[test]
+    fn all_smoothers_implement_the_trait() {
+        let mut smoothers: Vec<Box<dyn Smoother>> = vec![
+            Box::new(TinySmoother::new(0.5, 1.0)),
+            Box::new(TinySmootherF32::new(0.5, 1.0)),
+            Box::new(AttackReleaseSmoother::new(0.5, 0.5, 1.0)),
+            Box::new(OneEuroSmoother::new(48_000.0, 1.0, 0.0, 1.0)),
+            Box::new(Log2Smoother::new(48_000.0, 1.0, 1.0)),
+            Box::new(Cascade2Smoother::new(0.5, 1.0)),
+        ];
+        for smoother in &mut smoothers {
+            let value = smoother.next(2.0);
+            assert!(value > 1.0 && value <= 2.0, "{value}");
+            assert_eq!(smoother.current_value(), value);
+            assert_eq!(smoother.next(f32::NAN), value);
+            smoother.reset();
+        }
+        // the One Euro filter passes its first value through after a reset
+        assert_eq!(smoothers[3].next(2.0), 2.0);
+        assert_eq!(smoothers[0].current_value(), 1.0);
+    }
+}
@@ -4,223 +4,2592 @@
 //! performance. It processes values using internal f64 precision while maintaining
 //!  an f32 interface, ensuring zero drift even over extended periods.
 //!
+//! # Bipolar parameters
+//!
+//! Despite the gain-centric helpers (`set_target_db()`, `is_silent()`, ...), the smoother
+//! itself is a linear filter that treats all values alike: a pan position, a pitch bend or a
+//! stereo width in -1..1 can be smoothed directly, including negative start values. The
+//! output crosses zero without any kink or pause, and a ramp from `-x` to `+x` is the exact
+//! mirror image of the ramp from `+x` to `-x`. Only the dB and silence helpers assume a gain.
+//!
+//! ```
+//! use audio_utils::{pan_gains, PanLaw, TinySmoother};
+//!
+//! // hard left to hard right
+//! let mut pan = TinySmoother::new(0.99, -1.0);
+//! let (left, right) = pan_gains(pan.next(1.0), PanLaw::EqualPower);
+//! ```
+//!
 //! # Performance
 //! Benchmarks show a __~4000x real-time factor__ on modern CPUs, allowing thousands
 //! of parallel instances in typical audio workloads.
+
+use crate::decibels::{db_to_volt_f, volt_to_db_f, MIN_GAIN};
+use crate::meter::level_to_db;
+use crate::pan::equal_power;
+use crate::smoother::Smoother;
+
 pub struct TinySmoother {
     /// Current filtered value (f64 for numerical stability)
     last_value: f64,
+    /// Value the smoother is heading to, see `set_target()`
+    target: f64,
     /// Value to reset to
     start_value: f32,
     /// Smoothing coefficient in range [0.0, 1.0)
     beta: f64,
+    /// Distance below which the value snaps exactly to the target (0.0 = never snap)
+    snap_threshold: f64,
+    /// Active transition of `beta` toward a new value, see `set_half_life_ramp()`
+    beta_ramp: Option<BetaRamp>,
+}
+
+/// A transition of the smoothing coefficient, linear in `ln(beta)`.
+struct BetaRamp {
+    from_ln_beta: f64,
+    to_ln_beta: f64,
+    to_beta: f64,
+    /// Number of steps taken so far
+    position: u32,
+    /// Number of steps until `to_beta` is reached
+    length: u32,
+}
+
+/// Computes the smoothing coefficient for which the output moves halfway toward a new
+/// target after `half_life_samples` samples: `beta = e^(-ln(2)/n)`.
+///
+/// The count starts with the first `next()` call using the new target, so the n-th call
+/// returns exactly halfway: the step response after `k` calls is `1 - beta^k`.
+///
+/// A half-life of 0.0 yields `beta = 0.0` (no smoothing). Half-lives longer than
+/// `MAX_STABLE_HALF_LIFE_SAMPLES` are clamped, since their `beta` would round to 1.0.
+#[inline]
+pub(crate) fn beta_from_half_life(half_life_samples: f64) -> f64 {
+    (-2.0_f64.ln() / half_life_samples.min(MAX_STABLE_HALF_LIFE_SAMPLES)).exp()
+}
+
+/// Longest half-life with `beta < 1.0`: `ln(2) * 2^53` samples (~4000 years at 48 kHz), for
+/// which `beta = e^(-2^-53)` is the largest f64 below 1.0.
+const MAX_STABLE_HALF_LIFE_SAMPLES: f64 = std::f64::consts::LN_2 / (f64::EPSILON / 2.0);
+
+/// Maximum number of samples `TinySmoother::settle()` runs: 2^24 (~6 minutes at 48 kHz).
+const MAX_SETTLE_SAMPLES: u32 = 1 << 24;
+
+/// Computes the smoothing coefficient of a one-pole lowpass with the given cutoff frequency:
+/// `beta = e^(-2π·cutoff/sample_rate)`.
+#[inline]
+pub(crate) fn beta_from_cutoff(sample_rate: f64, cutoff_hz: f64) -> f64 {
+    (-std::f64::consts::TAU * cutoff_hz / sample_rate).exp()
+}
+
+/// Inverse of `beta_from_half_life()`: `-ln(2)/ln(beta)`, 0.0 for `beta == 0.0`.
+#[inline]
+fn half_life_from_beta(beta: f64) -> f64 {
+    if beta == 0.0 {
+        return 0.0;
+    }
+    -(2.0_f64.ln()) / beta.ln()
+}
+
+/// Number of samples to move 63.2% toward a target: `-1/ln(beta)`, 0.0 for `beta == 0.0`.
+#[inline]
+fn time_constant_from_beta(beta: f64) -> f64 {
+    if beta == 0.0 {
+        return 0.0;
+    }
+    -1.0 / beta.ln()
+}
+
+/// Converts a time in milliseconds into a (fractional) number of samples.
+#[inline]
+pub(crate) fn ms_to_samples(sample_rate: f32, ms: f32) -> f64 {
+    ms as f64 * sample_rate as f64 / 1000.0
+}
+
+impl Default for TinySmoother {
+    /// Creates a smoother with ~10ms half-life at common audio sample rates.
+    ///
+    /// The default configuration has a half-life of exactly 500 samples, which corresponds
+    /// to ~10ms at 48 kHz or ~11ms at 44.1 kHz: after a step from rest, the 500th call to
+    /// `next()` (counted from the first call with the new target) returns 50% of the step.
+    ///
+    /// The default configuration starts at 0.0 (silence).
+    fn default() -> TinySmoother {
+        // Beta calculation for 500-sample half-life:
+        // The n-th call after a step returns (1 - beta^n) * target, we want 0.5 * target
+        // for n = 500. This gives us: beta = e^(-ln(2)/500)
+        let beta = beta_from_half_life(500.0);
+        TinySmoother::new(beta, 0.0)
+    }
 }
 
-impl Default for TinySmoother {
-    /// Creates a smoother with ~10ms half-life at common audio sample rates.
-    ///
-    /// The default configuration reaches 50% of the target value after approximately
-    /// 500 samples, which corresponds to ~10ms at 48 kHz or ~11ms at 44.1 kHz.
-    ///
-    /// The default configuration starts at 0.0 (silence).
-    fn default() -> TinySmoother {
-        // Beta calculation for 500-sample half-life:
-        // At sample n=500, we want output = 0.5 * target
-        // This gives us: beta = e^(-ln(2)/500)
-        let beta = (-2.0_f64.ln() / 500.0).exp();
-        TinySmoother::new(beta, 0.0)
+impl TinySmoother {
+    /// Returns a builder for configuring a smoother by times instead of a raw `beta`.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// let smoother = TinySmoother::builder()
+    ///     .sample_rate(48_000.0)
+    ///     .half_life_ms(20.0)
+    ///     .start_value(1.0)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn builder() -> TinySmootherBuilder {
+        TinySmootherBuilder::default()
+    }
+
+    /// Creates a smoother with a custom smoothing coefficient.
+    ///
+    /// # Parameters
+    /// * `beta` - Smoothing coefficient in range (0.0, 1.0)
+    ///   - Values near 0.0 give instant response (no smoothing)
+    ///   - Values near 1.0 give slower smoothing
+    ///   - Values equal to or greater than 1.0 are unstable
+    ///
+    /// To calculate beta for a specific time constant:
+    /// * For 50% at n samples: `beta = e^(-ln(2)/n)`
+    /// * For 63.2% at n samples: `beta = e^(-1/n)`
+    ///
+    /// * `start_value` - the value the smoother should start from when reset (usually 0.0 or 1.0)
+    /// 
+    /// # Panics
+    ///
+    /// Panics if `beta` is not in range [0.0, 1.0) or if `start_value` is not finite.
+    /// 
+    pub fn new(beta: f64, start_value: f32) -> TinySmoother {
+        assert!(
+            beta >= 0.0 && beta < 1.0,
+            "Beta must be in range [0.0, 1.0), got {}",
+            beta
+        );
+        assert!(
+            start_value.is_finite(),
+            "Start value must be finite, got {}",
+            start_value
+        );
+        TinySmoother {
+            last_value: start_value as f64,
+            target: start_value as f64,
+            beta,
+            start_value,
+            snap_threshold: 0.0,
+            beta_ramp: None,
+        }
+    }
+
+    /// Creates a smoother already sitting at `initial_target`, so the first block does not
+    /// ramp toward the parameter's initial value (e.g. an audible fade-in on plugin load).
+    ///
+    /// `reset()` still returns to `start_value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `beta` is not in range [0.0, 1.0) or if `start_value` or `initial_target`
+    /// is not finite.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// // resets to silence, but starts at unity gain
+    /// let mut smoother = TinySmoother::with_initial_target(0.99, 0.0, 1.0);
+    ///
+    /// assert_eq!(smoother.next(1.0), 1.0);
+    /// ```
+    pub fn with_initial_target(beta: f64, start_value: f32, initial_target: f32) -> TinySmoother {
+        assert!(
+            initial_target.is_finite(),
+            "Initial target must be finite, got {}",
+            initial_target
+        );
+        let mut smoother = TinySmoother::new(beta, start_value);
+        smoother.last_value = initial_target as f64;
+        smoother.target = initial_target as f64;
+        smoother
+    }
+
+    /// Creates a smoother specified as a one-pole lowpass with the given cutoff frequency, via
+    /// the analog-prototype mapping `beta = e^(-2π·cutoff/sample_rate)`.
+    ///
+    /// The response is 3 dB down at `cutoff_hz` as long as the cutoff is well below the
+    /// Nyquist frequency. A cutoff at or above Nyquist disables smoothing (`beta = 0.0`).
+    ///
+    /// # Parameters
+    /// * `sample_rate` - Sample rate in Hz
+    /// * `cutoff_hz` - Cutoff (-3 dB) frequency in Hz
+    /// * `start_value` - Value after creation and `reset()`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sample_rate` or `cutoff_hz` is not finite and positive, or if `start_value`
+    /// is not finite.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// // 20 Hz lowpass, roughly a 5.5 ms half-life
+    /// let smoother = TinySmoother::from_cutoff_hz(48_000.0, 20.0, 0.0);
+    /// assert!((smoother.half_life_ms(48_000.0) - 5.52).abs() < 0.01);
+    /// ```
+    pub fn from_cutoff_hz(sample_rate: f32, cutoff_hz: f32, start_value: f32) -> TinySmoother {
+        assert!(
+            sample_rate.is_finite() && sample_rate > 0.0,
+            "Sample rate must be finite and positive, got {}",
+            sample_rate
+        );
+        assert!(
+            cutoff_hz.is_finite() && cutoff_hz > 0.0,
+            "Cutoff must be finite and positive, got {}",
+            cutoff_hz
+        );
+        let beta = if cutoff_hz >= sample_rate / 2.0 {
+            0.0
+        } else {
+            beta_from_cutoff(sample_rate as f64, cutoff_hz as f64)
+        };
+        TinySmoother::new(beta, start_value)
+    }
+
+    /// Processes the next target value with exponential smoothing.
+    ///
+    /// The filter converges smoothly toward the target using an error-feedback
+    /// approach that guarantees numerical stability. Once the target is reached,
+    /// the output remains exactly at the target value without drift.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// let mut smoother = TinySmoother::default();
+    /// let smoothed = smoother.next(1.0);  // Start transition to 1.0
+    /// ```
+    ///
+    /// # Real-time safety
+    ///
+    /// Never panics and never allocates in release builds, for any input including `NaN`,
+    /// infinities and subnormals.
+    ///
+    /// # Performance
+    ///
+    /// The check for non-finite targets is a branch that is practically always predicted
+    /// correctly. The branchless select of `next_branchless()` measures the same within noise
+    /// (`gain_loop` in `benches/smoothing.rs`): each output depends on the previous one, so the
+    /// latency of the recurrence dominates, and caller loops can't be vectorised across
+    /// samples anyway.
+    ///
+    /// With `beta == 0.0` (smoothing disabled) finite targets are passed through directly,
+    /// skipping the filter arithmetic (`passthrough` in `benches/smoothing.rs`).
+    pub fn next(&mut self, target: f32) -> f32 {
+        if !target.is_finite() {
+            return self.last_value as f32;
+        }
+        if self.beta == 0.0 && self.beta_ramp.is_none() {
+            self.target = target as f64;
+            self.last_value = self.target;
+            return target;
+        }
+        self.target = target as f64;
+        self.step() as f32
+    }
+
+    /// `next()` with a branchless select instead of the check for non-finite targets, with
+    /// identical results. Only kept for the comparison in `benches/smoothing.rs`.
+    #[doc(hidden)]
+    #[inline]
+    pub fn next_branchless(&mut self, target: f32) -> f32 {
+        let finite = target.is_finite();
+        if finite && self.beta_ramp.is_some() {
+            self.advance_beta_ramp();
+        }
+        // all ones for a finite target, all zeros otherwise
+        let mask = (finite as u64).wrapping_neg();
+        let select = |a: f64, b: f64| f64::from_bits((a.to_bits() & mask) | (b.to_bits() & !mask));
+
+        let previous = self.last_value;
+        self.target = select(target as f64, self.target);
+        let new_value = self.target - self.beta * (self.target - previous);
+        let new_value = self.update(new_value);
+        self.last_value = select(new_value, previous);
+        self.last_value as f32
+    }
+    /// Processes the next target value like `next()` and also reports whether the output
+    /// moved, e.g. to repaint a GUI or recompute a filter coefficient only when needed.
+    ///
+    /// The flag is `true` if the output differs from the previous output by more than the
+    /// snap threshold (see `set_snap_threshold()`), i.e. by any amount without a threshold.
+    /// Once the output has settled on the target, it is `false`. Within a few f32 steps of
+    /// the target it can already be `false` while the f64 state still moves, because
+    /// consecutive outputs round to the same f32 value.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// let mut smoother = TinySmoother::new(0.5, 0.0);
+    ///
+    /// assert_eq!(smoother.next_with_changed(1.0), (0.5, true));
+    /// assert_eq!(smoother.next_with_changed(f32::NAN), (0.5, false));
+    /// ```
+    ///
+    /// # Real-time safety
+    ///
+    /// Never panics and never allocates in release builds, like `next()`.
+    pub fn next_with_changed(&mut self, target: f32) -> (f32, bool) {
+        let previous = self.last_value as f32;
+        let value = self.next(target);
+        (value, (value - previous).abs() as f64 > self.snap_threshold)
+    }
+
+    /// Smooths a gain like `next()` and returns its reciprocal `1 / gain`, e.g. the makeup
+    /// factor that undoes a smoothed attenuation.
+    ///
+    /// The gain itself is smoothed, so the trajectory stays exponential in gain space, like
+    /// the attenuation it compensates. Smoothing `1 / gain_target` directly would follow a
+    /// different, warped curve. To avoid a division by zero, magnitudes below -100 dB
+    /// (`MIN_GAIN`) are limited to `MIN_GAIN`, i.e. the reciprocal stays within ±1e5 and keeps
+    /// the sign of the gain.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// let mut gain = TinySmoother::new(0.5, 1.0);
+    ///
+    /// assert_eq!(gain.next_reciprocal(0.5), 1.0 / 0.75);
+    /// assert_eq!(gain.current_value_f64(), 0.75);
+    /// ```
+    ///
+    /// # Real-time safety
+    ///
+    /// Never panics and never allocates in release builds, like `next()`.
+    pub fn next_reciprocal(&mut self, gain_target: f32) -> f32 {
+        let gain = self.next(gain_target);
+        (1.0 / gain.abs().max(MIN_GAIN)).copysign(gain)
+    }
+
+    /// Processes the next target value like `next()`, but in f64.
+    ///
+    /// The smoother keeps its state in f64 anyway, so f64 targets skip one cast and the
+    /// output preserves the full internal precision instead of being rounded to f32.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// let mut smoother = TinySmoother::default();
+    /// let smoothed: f64 = smoother.next_f64(1.0);
+    /// ```
+    ///
+    /// # Real-time safety
+    ///
+    /// Never panics and never allocates in release builds, like `next()`.
+    pub fn next_f64(&mut self, target: f64) -> f64 {
+        if !target.is_finite() {
+            return self.last_value;
+        }
+        self.target = target;
+        self.step()
+    }
+
+    /// Advances the smoother by `n` samples toward `target` in O(1) and returns the final value.
+    ///
+    /// The result equals `n` sequential `next(target)` calls (up to rounding), computed in
+    /// closed form: `last = target - (target - last) * beta^n`. Useful for offline rendering
+    /// or to pre-warm the state. Non-finite targets are ignored and return the current value.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// let mut smoother = TinySmoother::default();
+    /// // one half-life
+    /// let value = smoother.skip(1.0, 500);
+    ///
+    /// assert!((value - 0.5).abs() < 1e-6);
+    /// ```
+    ///
+    /// An active `set_half_life_ramp()` transition is completed before skipping.
+    ///
+    /// # Real-time safety
+    ///
+    /// Never panics and never allocates in release builds, for any target and any `n`.
+    pub fn skip(&mut self, target: f32, n: u32) -> f32 {
+        if !target.is_finite() {
+            return self.last_value as f32;
+        }
+        self.target = target as f64;
+        if n == 0 {
+            return self.last_value as f32;
+        }
+        if let Some(ramp) = self.beta_ramp.take() {
+            self.beta = ramp.to_beta;
+        }
+        let new_value = self.target - (self.target - self.last_value) * self.beta.powf(n as f64);
+        self.update(new_value) as f32
+    }
+
+    /// Advances the smoother by `samples` samples toward `target` without producing output,
+    /// e.g. to warm it up before playback so the first audible block is already near the
+    /// target.
+    ///
+    /// Uses the closed form of `skip()` and costs the same regardless of `samples`.
+    /// Non-finite targets are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// let mut gain = TinySmoother::builder()
+    ///     .sample_rate(48_000.0)
+    ///     .half_life_ms(1.0)
+    ///     .build()
+    ///     .unwrap();
+    /// gain.prime(1.0, 1000);
+    ///
+    /// assert!(gain.next(1.0) > 0.99999);
+    /// ```
+    #[inline]
+    pub fn prime(&mut self, target: f32, samples: u32) {
+        self.skip(target, samples);
+    }
+
+    /// Runs the smoother toward `target` until it has settled, then sets the value exactly
+    /// to the target and returns the number of samples it took.
+    ///
+    /// The smoother has settled once its f32 output equals the target, or once it snaps to
+    /// the target (see `set_snap_threshold()`). Without snapping, this happens when the
+    /// remaining distance drops below half an f32 ulp of the target, e.g. after 25
+    /// half-lives for a step from 0.0 to 1.0. The run is capped at 2^24 samples (~6 minutes
+    /// at 48 kHz), after which the value jumps to the target.
+    /// Useful for offline rendering and to precompute steady states; `samples_to_reach()`
+    /// gives the closed-form count for a fraction of the distance. Non-finite targets are
+    /// ignored and settle toward the current target.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// let mut smoother = TinySmoother::default();
+    /// let samples = smoother.settle(1.0);
+    ///
+    /// // 25 half-lives of 500 samples
+    /// assert!((12_500..=12_501).contains(&samples));
+    /// assert_eq!(smoother.next(1.0), 1.0);
+    /// ```
+    pub fn settle(&mut self, target: f32) -> u32 {
+        self.set_target(target);
+        let target = self.target as f32;
+        let mut samples = 0;
+        while self.is_smoothing() && self.last_value as f32 != target {
+            if samples == MAX_SETTLE_SAMPLES {
+                break;
+            }
+            self.step();
+            samples += 1;
+        }
+        self.last_value = self.target;
+        samples
+    }
+
+    /// Processes the next target value like `next()` and clamps the result to `[min, max]`.
+    ///
+    /// The internal state is clamped as well, so a target outside the range can't build up
+    /// an out-of-range value that keeps leaking into the output after the target returns:
+    /// smoothing resumes right from the boundary.
+    ///
+    /// An inverted range (`min > max`) or a `NaN` bound is a caller bug: it fails a debug
+    /// assertion, in release builds the bounds are swapped and a `NaN` bound is ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// // filter cutoff that must stay below Nyquist
+    /// let mut smoother = TinySmoother::new(0.5, 20_000.0);
+    /// let cutoff = smoother.next_clamped(30_000.0, 20.0, 24_000.0);
+    ///
+    /// assert_eq!(cutoff, 24_000.0);
+    /// ```
+    ///
+    /// # Real-time safety
+    ///
+    /// Never panics and never allocates in release builds, like `next()`.
+    pub fn next_clamped(&mut self, target: f32, min: f32, max: f32) -> f32 {
+        debug_assert!(min <= max, "Invalid range [{}, {}]", min, max);
+        let (low, high) = if min > max { (max, min) } else { (min, max) };
+        self.next(target);
+        // `max()`/`min()` instead of `clamp()`, which panics on NaN: a NaN bound is ignored
+        self.last_value = self.last_value.max(low as f64).min(high as f64);
+        self.last_value as f32
+    }
+
+    /// Maps a normalized parameter value (e.g. a knob in `0.0..=1.0`) through `map` and
+    /// smooths the mapped value.
+    ///
+    /// Smoothing happens after the mapping, in the domain the value is used in. Smoothing the
+    /// normalized value and mapping the output instead would warp the curve: with an
+    /// exponential frequency map, the same half-life would sweep quickly through the high
+    /// and slowly through the low frequencies. Non-finite mapped values are ignored like in
+    /// `next()`.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// // cutoff knob from 20 Hz to 20 kHz
+    /// let to_hz = |norm: f32| 20.0 * 1000.0_f32.powf(norm);
+    /// let mut cutoff = TinySmoother::new(0.99, 1000.0);
+    ///
+    /// let hz = cutoff.next_mapped(1.0, to_hz);
+    /// assert!(hz > 1000.0 && hz < 20_000.0);
+    /// ```
+    #[inline]
+    pub fn next_mapped(&mut self, norm: f32, map: impl Fn(f32) -> f32) -> f32 {
+        self.next(map(norm))
+    }
+
+    /// Smooths a mix `control` and returns the linear blend `a * (1 - g) + b * g`, where `g`
+    /// is the smoothed control, e.g. for a dry/wet mix in one call per sample.
+    ///
+    /// The control is clamped to [0.0, 1.0], `NaN` is ignored like in `next()`. Create the
+    /// smoother with the initial mix as start value. A linear blend keeps the level of
+    /// correlated signals (e.g. dry and a gently filtered wet), see `blend_equal_power()`
+    /// for uncorrelated ones.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// let mut mix = TinySmoother::new(0.99, 0.0);
+    /// let (dry, wet) = (0.5, -0.25);
+    ///
+    /// assert_eq!(mix.blend(dry, wet, 0.0), dry);
+    /// let sample = mix.blend(dry, wet, 1.0); // starts moving toward `wet`
+    /// ```
+    ///
+    /// # Real-time safety
+    ///
+    /// Never panics and never allocates, like `next()`.
+    #[inline]
+    pub fn blend(&mut self, a: f32, b: f32, control: f32) -> f32 {
+        let g = self.next(control.clamp(0.0, 1.0));
+        a * (1.0 - g) + b * g
+    }
+
+    /// Like `blend()`, but with equal-power gains `cos(g·π/2)` and `sin(g·π/2)`, which keep
+    /// the level of uncorrelated signals (e.g. dry and a reverb) constant across the mix.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// let mut mix = TinySmoother::new(0.0, 0.5);
+    ///
+    /// // -3 dB each at the center
+    /// assert!((mix.blend_equal_power(1.0, 0.0, 0.5) - 0.7071).abs() < 1e-4);
+    /// ```
+    ///
+    /// # Real-time safety
+    ///
+    /// Never panics and never allocates, like `next()`.
+    #[inline]
+    pub fn blend_equal_power(&mut self, a: f32, b: f32, control: f32) -> f32 {
+        let g = self.next(control.clamp(0.0, 1.0));
+        let (gain_a, gain_b) = equal_power(g);
+        a * gain_a + b * gain_b
+    }
+
+    /// Processes the next target value like `next()`, but first jumps to within `threshold`
+    /// of the target if the current value is further away.
+    ///
+    /// Only the last `threshold` of a large jump is smoothed, so e.g. a preset change by
+    /// 40 dB settles as quickly as a small change instead of swelling slowly, while
+    /// automation moving less than `threshold` per call stays fully smooth. Non-finite
+    /// targets are ignored and return the current value.
+    ///
+    /// A negative or `NaN` threshold is a caller bug: it fails a debug assertion, in release
+    /// builds it is treated as 0.0 (jump straight to the target).
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// let mut smoother = TinySmoother::new(0.5, 0.0);
+    ///
+    /// // small change: smoothed as usual
+    /// assert_eq!(smoother.jump_if_far(0.2, 0.25), 0.1);
+    /// // large change: jumps to 1.75 first, then smooths the remaining 0.25
+    /// assert_eq!(smoother.jump_if_far(2.0, 0.25), 1.875);
+    /// ```
+    ///
+    /// # Real-time safety
+    ///
+    /// Never panics and never allocates in release builds, like `next()`.
+    pub fn jump_if_far(&mut self, target: f32, threshold: f32) -> f32 {
+        debug_assert!(threshold >= 0.0, "Threshold must be non-negative, got {}", threshold);
+        if !target.is_finite() {
+            return self.last_value as f32;
+        }
+        let target_f64 = target as f64;
+        // also maps NaN to 0.0
+        let threshold = if threshold >= 0.0 { threshold as f64 } else { 0.0 };
+        let distance = target_f64 - self.last_value;
+        if distance.abs() > threshold {
+            self.last_value = target_f64 - threshold.copysign(distance);
+        }
+        self.next(target)
+    }
+
+    /// Returns an iterator yielding the smoothed value for each target in `targets`.
+    ///
+    /// Each item advances the smoother like `next()`, which fits sample-accurate automation
+    /// delivering one target per sample. The smoother stays borrowed while iterating.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// let mut smoother = TinySmoother::default();
+    /// let targets = [1.0f32; 64];
+    /// let mut buffer = [0.5f32; 64];
+    ///
+    /// for (sample, gain) in buffer.iter_mut().zip(smoother.smooth_iter(&targets)) {
+    ///     *sample *= gain;
+    /// }
+    /// ```
+    pub fn smooth_iter<'a>(&'a mut self, targets: &'a [f32]) -> impl Iterator<Item = f32> + 'a {
+        targets.iter().map(move |&target| self.next(target))
+    }
+
+    /// Applies the smoothed gain to a planar (non-interleaved) multi-channel buffer.
+    ///
+    /// The smoother advances once per frame toward `target`, and the samples of all
+    /// `channels` at that frame index are multiplied by the same gain, which keeps the
+    /// channels aligned. This matches hosts that hand out one slice per channel; see
+    /// `apply_smoothed_gain()` for interleaved buffers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the channel slices differ in length.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// let mut smoother = TinySmoother::default();
+    /// let mut left = [0.5f32; 64];
+    /// let mut right = [0.5f32; 64];
+    ///
+    /// smoother.process_frames(1.0, &mut [&mut left, &mut right]);
+    /// assert_eq!(left, right);
+    /// ```
+    pub fn process_frames(&mut self, target: f32, channels: &mut [&mut [f32]]) {
+        let frames = channels.first().map_or(0, |channel| channel.len());
+        assert!(
+            channels.iter().all(|channel| channel.len() == frames),
+            "All channels must have the same length"
+        );
+        for frame in 0..frames {
+            let gain = self.next(target);
+            for channel in channels.iter_mut() {
+                channel[frame] *= gain;
+            }
+        }
+    }
+
+    /// Applies the smoothed gain to an interleaved multi-channel buffer like
+    /// `apply_smoothed_gain()` and returns the peak level of the output in dB, measured in
+    /// the same pass instead of a second `peak_db()` over the buffer.
+    ///
+    /// The peak follows `peak_db()`: -100.0 for silence or an empty buffer, non-finite
+    /// samples are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `channels` is 0 or `buffer.len()` is not a multiple of `channels`.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// let mut gain = TinySmoother::new(0.0, 1.0);
+    /// // interleaved stereo: L R L R ...
+    /// let mut buffer = [0.5f32, -1.0, 0.25, 0.5];
+    ///
+    /// let peak = gain.apply_and_peak(0.5, &mut buffer, 2);
+    /// assert!((peak + 6.02).abs() < 0.01);
+    /// ```
+    pub fn apply_and_peak(&mut self, target: f32, buffer: &mut [f32], channels: usize) -> f32 {
+        assert!(channels > 0, "Channel count must be greater than 0");
+        assert!(
+            buffer.len().is_multiple_of(channels),
+            "Buffer length {} is not a multiple of the channel count {}",
+            buffer.len(),
+            channels
+        );
+        let mut peak = 0.0f32;
+        for frame in buffer.chunks_exact_mut(channels) {
+            let gain = self.next(target);
+            for sample in frame {
+                *sample *= gain;
+                let level = sample.abs();
+                if level.is_finite() {
+                    peak = peak.max(level);
+                }
+            }
+        }
+        level_to_db(peak)
+    }
+
+    /// Sets the target for the following `tick()` calls. Non-finite targets are ignored.
+    ///
+    /// `next(target)` is equivalent to `set_target(target)` followed by `tick()`. The split
+    /// is useful when the target changes once per block but the value is needed per sample.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// let mut smoother = TinySmoother::default();
+    /// smoother.set_target(1.0);
+    ///
+    /// let smoothed = smoother.tick();
+    /// assert!(smoother.is_smoothing());
+    /// ```
+    pub fn set_target(&mut self, target: f32) {
+        if target.is_finite() {
+            self.target = target as f64;
+        }
+    }
+
+    /// Sets the target for the following `tick()` calls from a level in dB.
+    ///
+    /// The dB value is converted into a linear gain via `db_to_volt_f()`, so the smoother
+    /// smooths the gain, driven straight from a dB parameter. `f32::NEG_INFINITY` targets
+    /// silence (0.0), `NaN` and `f32::INFINITY` are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// let mut smoother = TinySmoother::new(0.5, 1.0);
+    /// smoother.set_target_db(-20.0);
+    ///
+    /// assert_eq!(smoother.tick(), 0.55);
+    /// ```
+    pub fn set_target_db(&mut self, db: f32) {
+        if db == f32::NEG_INFINITY {
+            self.target = 0.0;
+        } else if db.is_finite() {
+            self.target = db_to_volt_f(db) as f64;
+        }
+    }
+
+    /// Advances the smoother by one sample toward the target set by `set_target()`.
+    ///
+    /// # Real-time safety
+    ///
+    /// Never panics and never allocates in release builds.
+    pub fn tick(&mut self) -> f32 {
+        self.step() as f32
+    }
+
+    /// Starts fading out toward 0.0, e.g. before a polyphonic synth steals the voice. The
+    /// following `tick()` calls ramp down, `is_silent()` reports when the voice can be reused.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// let mut amplitude = TinySmoother::new(0.99, 0.0);
+    /// amplitude.reset_to(1.0);
+    ///
+    /// amplitude.begin_release();
+    /// while !amplitude.is_silent() {
+    ///     let gain = amplitude.tick();
+    ///     // render the voice with `gain`
+    /// }
+    /// ```
+    pub fn begin_release(&mut self) {
+        self.target = 0.0;
+    }
+
+    /// Returns `true` if the current value is below -100 dB (`MIN_GAIN`) in magnitude, i.e.
+    /// inaudible.
+    pub fn is_silent(&self) -> bool {
+        self.last_value.abs() < MIN_GAIN as f64
+    }
+
+    /// Returns `true` while the current value differs from the target.
+    ///
+    /// Without a snap threshold (see `set_snap_threshold()`), the value approaches the
+    /// target only asymptotically and may keep smoothing for a long time.
+    pub fn is_smoothing(&self) -> bool {
+        self.last_value != self.target
+    }
+
+    /// One step of the recurrence toward the target, including snapping.
+    #[inline]
+    fn step(&mut self) -> f64 {
+        debug_assert!(self.last_value.is_finite() && self.target.is_finite());
+        if self.beta_ramp.is_some() {
+            self.advance_beta_ramp();
+        }
+        let new_value = self.target - self.beta * (self.target - self.last_value);
+        self.update(new_value)
+    }
+
+    /// Moves `beta` one step along the active ramp and ends the ramp at its last step.
+    #[cold]
+    fn advance_beta_ramp(&mut self) {
+        let Some(ramp) = self.beta_ramp.as_mut() else {
+            return;
+        };
+        ramp.position += 1;
+        if ramp.position >= ramp.length {
+            self.beta = ramp.to_beta;
+            self.beta_ramp = None;
+        } else {
+            let t = ramp.position as f64 / ramp.length as f64;
+            self.beta = (ramp.from_ln_beta + (ramp.to_ln_beta - ramp.from_ln_beta) * t).exp();
+        }
+    }
+
+    /// Stores a new value, snapping it to the target if it is closer than the snap threshold.
+    #[inline]
+    fn update(&mut self, new_value: f64) -> f64 {
+        self.last_value = if (self.target - new_value).abs() < self.snap_threshold {
+            self.target
+        } else {
+            new_value
+        };
+        self.last_value
+    }
+
+    /// Sets the distance to the target below which the output snaps exactly to the target.
+    ///
+    /// Exponential smoothing approaches the target asymptotically. With a snap threshold,
+    /// the output becomes bit-exactly equal to the target once it is closer than `eps`, and
+    /// stays there. The default threshold is 0.0, which disables snapping.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `eps` is negative or not finite.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// let mut smoother = TinySmoother::default();
+    /// smoother.set_snap_threshold(1e-4);
+    ///
+    /// let settled = (0..10_000).map(|_| smoother.next(1.0)).last();
+    /// assert_eq!(settled, Some(1.0));
+    /// ```
+    pub fn set_snap_threshold(&mut self, eps: f32) {
+        assert!(
+            eps >= 0.0 && eps.is_finite(),
+            "Snap threshold must be finite and non-negative, got {}",
+            eps
+        );
+        self.snap_threshold = eps as f64;
+    }
+
+    /// Changes the smoothing time gradually instead of instantly.
+    ///
+    /// Over the next `morph_samples` calls to `next()`/`tick()`, `beta` moves from its current
+    /// value toward the value for `target_half_life_ms`, linearly in `ln(beta)` so the change
+    /// is perceptually even. This way a "smoothing speed" macro control can move without
+    /// clicks. The current value and the target are not touched. With `morph_samples == 0`,
+    /// the new smoothing time applies immediately.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `target_half_life_ms` is negative, `sample_rate` is not positive, or the
+    /// resulting `beta` is not in range [0.0, 1.0).
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// let mut smoother = TinySmoother::default();
+    /// // from ~10 ms to 1 ms half-life over 100 ms
+    /// smoother.set_half_life_ramp(1.0, 48_000.0, 4800);
+    /// ```
+    pub fn set_half_life_ramp(&mut self, target_half_life_ms: f32, sample_rate: f32, morph_samples: u32) {
+        assert!(
+            sample_rate.is_finite() && sample_rate > 0.0,
+            "Sample rate must be finite and positive, got {}",
+            sample_rate
+        );
+        assert!(
+            target_half_life_ms >= 0.0,
+            "Half-life must be non-negative, got {}",
+            target_half_life_ms
+        );
+        let to_beta = beta_from_half_life(ms_to_samples(sample_rate, target_half_life_ms));
+        assert!(
+            (0.0..1.0).contains(&to_beta),
+            "Beta must be in range [0.0, 1.0), got {}",
+            to_beta
+        );
+        if morph_samples == 0 || to_beta == 0.0 || self.beta == 0.0 {
+            // `ln(0)` can't be interpolated: switch to or from "no smoothing" at once.
+            self.beta = to_beta;
+            self.beta_ramp = None;
+            return;
+        }
+        self.beta_ramp = Some(BetaRamp {
+            from_ln_beta: self.beta.ln(),
+            to_ln_beta: to_beta.ln(),
+            to_beta,
+            position: 0,
+            length: morph_samples,
+        });
+    }
+
+    /// Sets the half-life in samples immediately: `beta = e^(-ln(2)/n)`.
+    ///
+    /// The current value and the target are not touched, an active `set_half_life_ramp()`
+    /// transition is cancelled. `n <= 0.0` means instant response (no smoothing), half-lives
+    /// longer than `max_stable_half_life_samples()` are clamped and `NaN` is ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// let mut smoother = TinySmoother::default();
+    /// smoother.set_half_life_samples(250.0);
+    ///
+    /// assert!((smoother.half_life_samples() - 250.0).abs() < 1e-6);
+    /// ```
+    pub fn set_half_life_samples(&mut self, n: f64) {
+        if n.is_nan() {
+            return;
+        }
+        self.beta = if n <= 0.0 { 0.0 } else { beta_from_half_life(n) };
+        self.beta_ramp = None;
+    }
+
+    /// Replaces the smoothing coefficient without touching the current value or target.
+    /// Used by filters with adaptive coefficients; `beta` must be in range [0.0, 1.0).
+    #[inline]
+    pub(crate) fn set_beta(&mut self, beta: f64) {
+        debug_assert!((0.0..1.0).contains(&beta));
+        self.beta = beta;
+    }
+
+    /// Returns the longest half-life in samples for which `beta` is still below 1.0 in f64.
+    ///
+    /// The time-based constructors (`TinySmoother::builder()`,
+    /// `SmoothingCoeffs::from_half_life_ms()`, `set_half_life_ramp()`, ...) clamp longer
+    /// half-lives to this value instead of panicking. When computing `beta` for
+    /// `TinySmoother::new()` yourself, clamp the half-life to it as well.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// let half_life = 1e20_f64.min(TinySmoother::max_stable_half_life_samples());
+    /// let beta = (-2.0_f64.ln() / half_life).exp();
+    ///
+    /// assert!(beta < 1.0);
+    /// let smoother = TinySmoother::new(beta, 0.0);
+    /// ```
+    pub fn max_stable_half_life_samples() -> f64 {
+        MAX_STABLE_HALF_LIFE_SAMPLES
+    }
+
+    /// Returns the smoothing coefficient. During a half-life ramp (see
+    /// `set_half_life_ramp()`) this is the coefficient of the current sample.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// let smoother = TinySmoother::new(0.99, 0.5);
+    /// let copy = TinySmoother::new(smoother.beta(), smoother.start_value());
+    /// ```
+    pub fn beta(&self) -> f64 {
+        self.beta
+    }
+
+    /// Returns the value the smoother starts from and returns to on `reset()`.
+    pub fn start_value(&self) -> f32 {
+        self.start_value
+    }
+
+    /// Returns the number of samples after which the output has moved halfway toward a new
+    /// target: `-ln(2)/ln(beta)`. Returns 0.0 for `beta == 0.0` (no smoothing).
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// let half_life = TinySmoother::default().half_life_samples();
+    /// assert!((half_life - 500.0).abs() < 1e-6);
+    /// ```
+    pub fn half_life_samples(&self) -> f64 {
+        half_life_from_beta(self.beta)
+    }
+
+    /// Returns the number of samples after which the output has moved 63.2% (`1 - 1/e`)
+    /// toward a new target: `-1/ln(beta)`. Returns 0.0 for `beta == 0.0` (no smoothing).
+    pub fn time_constant_samples(&self) -> f64 {
+        time_constant_from_beta(self.beta)
+    }
+
+    /// Returns the number of samples after which a step from `from` to `to` has covered
+    /// `fraction` of the distance, i.e. the output reaches `from + fraction * (to - from)`.
+    ///
+    /// Computed in closed form as `ln(1 - fraction) / ln(beta)` without running the
+    /// smoother; the result is fractional, the output crosses the level between the
+    /// neighbouring integer sample counts. `fraction = 0.5` yields `half_life_samples()`.
+    ///
+    /// # Returns
+    ///
+    /// * 0.0 for `fraction <= 0.0` or `NaN`, `from == to`, or `beta == 0.0` (no smoothing)
+    /// * `f64::INFINITY` for `fraction >= 1.0`: the target is approached asymptotically
+    ///
+    /// A snap threshold (see `set_snap_threshold()`) is not taken into account.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// let smoother = TinySmoother::default();
+    /// let samples = smoother.samples_to_reach(0.0, 1.0, 0.9);
+    ///
+    /// assert!((samples - 1661.0).abs() < 1.0);
+    /// ```
+    pub fn samples_to_reach(&self, from: f32, to: f32, fraction: f32) -> f64 {
+        if fraction.is_nan() || fraction <= 0.0 || from == to {
+            return 0.0;
+        }
+        if fraction >= 1.0 {
+            return f64::INFINITY;
+        }
+        if self.beta == 0.0 {
+            return 0.0;
+        }
+        (1.0 - fraction as f64).ln() / self.beta.ln()
+    }
+
+    /// Returns the half-life (see `half_life_samples()`) in milliseconds at `sample_rate`.
+    pub fn half_life_ms(&self, sample_rate: f32) -> f64 {
+        self.half_life_samples() * 1000.0 / sample_rate as f64
+    }
+
+    /// Returns the time constant (see `time_constant_samples()`) in milliseconds at
+    /// `sample_rate`.
+    pub fn time_constant_ms(&self, sample_rate: f32) -> f64 {
+        self.time_constant_samples() * 1000.0 / sample_rate as f64
+    }
+
+    /// Returns the current (most recently produced) value with full internal f64 precision.
+    pub fn current_value_f64(&self) -> f64 {
+        self.last_value
+    }
+
+    /// Returns the current (most recently produced) value in dB, e.g. to show a smoothed
+    /// gain catching up with its fader: `volt_to_db_f()` of the current value.
+    ///
+    /// Silence (0.0) reports `f32::NEG_INFINITY`, other values are clamped to [-100, 27] dB.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// let mut gain = TinySmoother::new(0.99, 0.5);
+    /// gain.next(1.0);
+    ///
+    /// let db = gain.current_db(); // a bit above -6 dB, rising toward 0 dB
+    /// assert!(db > -6.1 && db < 0.0);
+    /// ```
+    pub fn current_db(&self) -> f32 {
+        volt_to_db_f(self.last_value as f32)
+    }
+
+    /// Resets the smoother to its starting value. The target is reset to the starting
+    /// value as well, so `tick()` does not ramp back toward a stale target.
+    ///
+    /// This is a hard reset: the value jumps, which clicks if audio is flowing. See
+    /// `reset_soft()` for a smooth return to the starting value.
+    ///
+    /// The starting value is determined at creation time:
+    /// - Via `TinySmoother::new()` where it's explicitly specified
+    /// - Via `TinySmoother::default()` where it defaults to 0.0
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// let mut smoother = TinySmoother::default();
+    /// // Run for 500 samples
+    /// for _ in 0..500 {
+    ///     smoother.next(1.0);
+    /// }
+    /// // Now the value should be close to 0.5
+    /// assert!(smoother.next(1.0) > 0.499);
+    ///
+    /// smoother.reset();
+    /// // After reset, the value should be close to 0.0
+    /// assert!(smoother.next(1.0) < 0.01);
+    /// ```
+    pub fn reset(&mut self) {
+        self.last_value = self.start_value as f64;
+        self.target = self.start_value as f64;
+    }
+
+    /// Sets the target to the starting value, so the following `tick()` calls ramp there
+    /// smoothly instead of jumping like `reset()`.
+    ///
+    /// Useful to fade a gain out when a plugin is bypassed. Note that `next(target)` sets a
+    /// new target and thereby overrides the soft reset.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// let mut smoother = TinySmoother::default();
+    /// smoother.reset_to(1.0);
+    /// smoother.reset_soft();
+    ///
+    /// let value = smoother.tick();
+    /// assert!(value < 1.0 && value > 0.99);
+    /// ```
+    pub fn reset_soft(&mut self) {
+        self.target = self.start_value as f64;
+    }
+
+    /// Sets the current value and the target immediately, without smoothing. The start value
+    /// used by `reset()` is not changed. Non-finite values are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// let mut smoother = TinySmoother::default();
+    /// smoother.reset_to(1.0);
+    ///
+    /// assert_eq!(smoother.next(1.0), 1.0);
+    /// ```
+    pub fn reset_to(&mut self, value: f32) {
+        if value.is_finite() {
+            self.last_value = value as f64;
+            self.target = value as f64;
+        }
+    }
+
+    /// Resets the smoother to its starting value like `reset()` and sets a new target, so the
+    /// following `tick()` calls ramp from the starting value toward `target`.
+    ///
+    /// Unlike `reset_to()`, the current value does not jump to `target`. A non-finite
+    /// `target` is ignored and leaves the target at the starting value.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::TinySmoother;
+    ///
+    /// let mut smoother = TinySmoother::default();
+    /// smoother.reset_and_set_target(1.0);
+    ///
+    /// let value = smoother.tick();
+    /// assert!(value > 0.0 && value < 0.01);
+    /// ```
+    pub fn reset_and_set_target(&mut self, target: f32) {
+        self.reset();
+        self.set_target(target);
+    }
+
+    /// Creates a smoother from shared coefficients, starting at their start value.
+    ///
+    /// # Example
+    /// ```
+    /// use audio_utils::{SmoothingCoeffs, TinySmoother};
+    ///
+    /// let coeffs = SmoothingCoeffs::from_half_life_ms(48_000.0, 10.0, 0.0);
+    /// let voices: Vec<TinySmoother> = (0..8).map(|_| TinySmoother::with_coeffs(&coeffs)).collect();
+    /// ```
+    pub fn with_coeffs(coeffs: &SmoothingCoeffs) -> TinySmoother {
+        TinySmoother::new(coeffs.beta, coeffs.start_value)
+    }
+
+    /// Takes over the smoothing coefficient and start value of `coeffs`. The current value
+    /// and the target are not touched, an active `set_half_life_ramp()` is cancelled.
+    pub fn apply_coeffs(&mut self, coeffs: &SmoothingCoeffs) {
+        self.beta = coeffs.beta;
+        self.start_value = coeffs.start_value;
+        self.beta_ramp = None;
+    }
+}
+
+impl Smoother for TinySmoother {
+    #[inline]
+    fn next(&mut self, target: f32) -> f32 {
+        TinySmoother::next(self, target)
+    }
+
+    fn reset(&mut self) {
+        TinySmoother::reset(self);
+    }
+
+    fn current_value(&self) -> f32 {
+        self.last_value as f32
+    }
+}
+
+/// Smoothing configuration shared by many smoothers, e.g. all voices of a synth.
+///
+/// The coefficients are computed once, for example on a sample rate change, and then pushed
+/// to every smoother via `TinySmoother::apply_coeffs()`. The struct is plain data, so it can
+/// be shared across threads in an `Arc`.
+///
+/// # Example
+/// ```
+/// use audio_utils::{SmoothingCoeffs, TinySmoother};
+/// use std::sync::Arc;
+///
+/// let mut voices = [TinySmoother::default(), TinySmoother::default()];
+///
+/// // on a sample rate change
+/// let coeffs = Arc::new(SmoothingCoeffs::from_half_life_ms(96_000.0, 10.0, 0.0));
+/// for voice in &mut voices {
+///     voice.apply_coeffs(&coeffs);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SmoothingCoeffs {
+    beta: f64,
+    start_value: f32,
+    half_life_samples: f64,
+    time_constant_samples: f64,
+}
+
+impl SmoothingCoeffs {
+    /// Creates coefficients from a raw smoothing coefficient, see `TinySmoother::new()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `beta` is not in range [0.0, 1.0) or if `start_value` is not finite.
+    pub fn new(beta: f64, start_value: f32) -> SmoothingCoeffs {
+        assert!(
+            (0.0..1.0).contains(&beta),
+            "Beta must be in range [0.0, 1.0), got {}",
+            beta
+        );
+        assert!(
+            start_value.is_finite(),
+            "Start value must be finite, got {}",
+            start_value
+        );
+        SmoothingCoeffs {
+            beta,
+            start_value,
+            half_life_samples: half_life_from_beta(beta),
+            time_constant_samples: time_constant_from_beta(beta),
+        }
+    }
+
+    /// Creates coefficients for the given half-life.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the half-life is negative, the sample rate not positive or `start_value`
+    /// not finite.
+    pub fn from_half_life_ms(sample_rate: f32, half_life_ms: f32, start_value: f32) -> SmoothingCoeffs {
+        assert!(
+            sample_rate.is_finite() && sample_rate > 0.0,
+            "Sample rate must be finite and positive, got {}",
+            sample_rate
+        );
+        assert!(
+            half_life_ms >= 0.0,
+            "Half-life must be non-negative, got {}",
+            half_life_ms
+        );
+        SmoothingCoeffs::new(
+            beta_from_half_life(ms_to_samples(sample_rate, half_life_ms)),
+            start_value,
+        )
+    }
+
+    /// Returns the smoothing coefficient.
+    pub fn beta(&self) -> f64 {
+        self.beta
+    }
+
+    /// Returns the value smoothers start from and return to on `reset()`.
+    pub fn start_value(&self) -> f32 {
+        self.start_value
+    }
+
+    /// Returns the half-life in samples, see `TinySmoother::half_life_samples()`.
+    pub fn half_life_samples(&self) -> f64 {
+        self.half_life_samples
+    }
+
+    /// Returns the time constant in samples, see `TinySmoother::time_constant_samples()`.
+    pub fn time_constant_samples(&self) -> f64 {
+        self.time_constant_samples
+    }
+}
+
+/// The step response of a `TinySmoother`, as an iterator over the output values.
+///
+/// Yields exactly what a smoother created with `TinySmoother::new(beta, from)` returns
+/// when fed `to` on every call, without touching any live smoother. Useful to generate
+/// data for documentation, tests or a GUI preview of the smoothing curve. The iterator
+/// never ends, limit it with `take()`.
+///
+/// # Example
+/// ```
+/// use audio_utils::StepResponse;
+///
+/// // half-life of 2 samples
+/// let beta = 0.5_f64.sqrt();
+/// let curve: Vec<f32> = StepResponse::new(beta, 0.0, 1.0).take(4).collect();
+///
+/// assert!((curve[1] - 0.5).abs() < 1e-6);
+/// assert!((curve[3] - 0.75).abs() < 1e-6);
+/// ```
+pub struct StepResponse {
+    smoother: TinySmoother,
+    to: f32,
+}
+
+impl StepResponse {
+    /// Creates the step response from `from` to `to`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `beta` is not in range [0.0, 1.0) or if `from` or `to` is not finite.
+    pub fn new(beta: f64, from: f32, to: f32) -> StepResponse {
+        assert!(to.is_finite(), "Target must be finite, got {}", to);
+        StepResponse {
+            smoother: TinySmoother::new(beta, from),
+            to,
+        }
+    }
+}
+
+impl Iterator for StepResponse {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        Some(self.smoother.next(self.to))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+/// Errors reported by `TinySmootherBuilder::build()`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SmootherError {
+    /// The half-life was given in milliseconds, but no sample rate was set.
+    MissingSampleRate,
+    /// The half-life was given both in milliseconds and in samples.
+    ConflictingHalfLife,
+    /// The sample rate is not finite and positive.
+    InvalidSampleRate(f32),
+    /// The half-life is not finite and non-negative.
+    InvalidHalfLife(f64),
+    /// The start value is not finite.
+    InvalidStartValue(f32),
+    /// The snap threshold is not finite and non-negative.
+    InvalidSnapThreshold(f32),
+}
+
+impl std::fmt::Display for SmootherError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SmootherError::MissingSampleRate => {
+                write!(f, "Half-life in milliseconds requires a sample rate")
+            }
+            SmootherError::ConflictingHalfLife => {
+                write!(f, "Half-life must be given either in milliseconds or in samples, not both")
+            }
+            SmootherError::InvalidSampleRate(value) => {
+                write!(f, "Sample rate must be finite and positive, got {}", value)
+            }
+            SmootherError::InvalidHalfLife(value) => {
+                write!(f, "Half-life must be finite and non-negative, got {}", value)
+            }
+            SmootherError::InvalidStartValue(value) => {
+                write!(f, "Start value must be finite, got {}", value)
+            }
+            SmootherError::InvalidSnapThreshold(value) => {
+                write!(f, "Snap threshold must be finite and non-negative, got {}", value)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SmootherError {}
+
+/// Builder for a `TinySmoother`, created by `TinySmoother::builder()`.
+///
+/// Settings that are not given default to the configuration of `TinySmoother::default()`:
+/// a half-life of 500 samples, a start value of 0.0 and no snapping.
+#[derive(Debug, Clone, Default)]
+pub struct TinySmootherBuilder {
+    half_life_ms: Option<f32>,
+    half_life_samples: Option<f64>,
+    sample_rate: Option<f32>,
+    start_value: Option<f32>,
+    snap_threshold: Option<f32>,
+}
+
+impl TinySmootherBuilder {
+    /// Sets the half-life in milliseconds, requires `sample_rate()`.
+    pub fn half_life_ms(mut self, half_life_ms: f32) -> Self {
+        self.half_life_ms = Some(half_life_ms);
+        self
+    }
+
+    /// Sets the half-life in samples.
+    pub fn half_life_samples(mut self, half_life_samples: f64) -> Self {
+        self.half_life_samples = Some(half_life_samples);
+        self
+    }
+
+    /// Sets the sample rate in Hz used to convert `half_life_ms()` into samples.
+    pub fn sample_rate(mut self, sample_rate: f32) -> Self {
+        self.sample_rate = Some(sample_rate);
+        self
+    }
+
+    /// Sets the value the smoother starts from and returns to on `reset()`.
+    pub fn start_value(mut self, start_value: f32) -> Self {
+        self.start_value = Some(start_value);
+        self
+    }
+
+    /// Sets the snap threshold, see `TinySmoother::set_snap_threshold()`.
+    pub fn snap_threshold(mut self, snap_threshold: f32) -> Self {
+        self.snap_threshold = Some(snap_threshold);
+        self
+    }
+
+    /// Validates the settings and creates the smoother.
+    pub fn build(self) -> Result<TinySmoother, SmootherError> {
+        if let Some(sample_rate) = self.sample_rate
+            && !(sample_rate.is_finite() && sample_rate > 0.0)
+        {
+            return Err(SmootherError::InvalidSampleRate(sample_rate));
+        }
+        let half_life_samples = match (self.half_life_ms, self.half_life_samples) {
+            (Some(_), Some(_)) => return Err(SmootherError::ConflictingHalfLife),
+            (Some(half_life_ms), None) => {
+                let sample_rate = self.sample_rate.ok_or(SmootherError::MissingSampleRate)?;
+                ms_to_samples(sample_rate, half_life_ms)
+            }
+            (None, Some(half_life_samples)) => half_life_samples,
+            (None, None) => 500.0,
+        };
+        if !(half_life_samples.is_finite() && half_life_samples >= 0.0) {
+            return Err(SmootherError::InvalidHalfLife(half_life_samples));
+        }
+
+        let start_value = self.start_value.unwrap_or(0.0);
+        if !start_value.is_finite() {
+            return Err(SmootherError::InvalidStartValue(start_value));
+        }
+
+        let snap_threshold = self.snap_threshold.unwrap_or(0.0);
+        if !(snap_threshold.is_finite() && snap_threshold >= 0.0) {
+            return Err(SmootherError::InvalidSnapThreshold(snap_threshold));
+        }
+
+        let mut smoother = TinySmoother::new(beta_from_half_life(half_life_samples), start_value);
+        smoother.set_snap_threshold(snap_threshold);
+        Ok(smoother)
+    }
+}
+
+//--- Tests ---------------------------------------------------------------------------------------
+//
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // smoother -------------
+    #[test]
+    fn smoother_reaches_half_target_within_500_samples() {
+        let mut tiny_smoother = TinySmoother::default();
+        // start at 0.0
+        let start = tiny_smoother.next(0.0);
+        assert_eq!(start, 0.0);
+
+        // target 1.0 for 500 samples
+        let target = 1.0f32;
+        for _ in 0..500 {
+            let _value = tiny_smoother.next(target);
+        }
+
+        // one call past the half-life, the value should be close to 0.5
+        let value = tiny_smoother.next(target);
+        assert!(value > 0.499 && value < 0.501);
+        println!("value = {value}.")
+    }
+
+    #[test]
+    fn default_smoother_crosses_half_target_on_call_500() {
+        let mut smoother = TinySmoother::default();
+        let values: Vec<f32> = (0..501).map(|_| smoother.next(1.0)).collect();
+
+        // values[k] is returned by call k + 1
+        assert!(values[498] < 0.5);
+        assert_eq!(values[499], 0.5);
+        assert!(values[500] > 0.5);
+        assert_eq!(values.iter().position(|&value| value >= 0.5), Some(499));
+    }
+
+    /// Feeds `samples` targets of a seeded random walk with occasional jumps to `next` and
+    /// checks that each output is finite and lies between the previous output and the
+    /// current target, i.e. never overshoots. This also keeps all outputs within the range
+    /// of the start value and the targets.
+    fn check_random_walk_bracketing(mut next: impl FnMut(f32) -> f32, start: f32, samples: usize) {
+        use rand::{Rng, SeedableRng, rngs::SmallRng};
+
+        let mut rng = SmallRng::seed_from_u64(0x0B5E_55ED);
+        let mut target = start;
+        let mut last = start;
+        for n in 0..samples {
+            target = if rng.random_ratio(1, 48_000) {
+                rng.random_range(-2.0..2.0)
+            } else {
+                (target + rng.random_range(-0.01..0.01)).clamp(-2.0, 2.0)
+            };
+            let value = next(target);
+            assert!(value.is_finite(), "sample {n}: {value}");
+            assert!(
+                value >= last.min(target) && value <= last.max(target),
+                "sample {n}: {value} not between {last} and {target}"
+            );
+            last = value;
+        }
+    }
+
+    #[test]
+    fn smoother_never_overshoots_a_random_walk() {
+        // one simulated minute each, see the ignored test for an hour
+        const SAMPLES: usize = 48_000 * 60;
+        let mut default = TinySmoother::default();
+        check_random_walk_bracketing(|target| default.next(target), 0.0, SAMPLES);
+        let mut fast = TinySmoother::new(beta_from_half_life(2.0), 0.5);
+        check_random_walk_bracketing(|target| fast.next(target), 0.5, SAMPLES);
+        let mut snapping = TinySmoother::default();
+        snapping.set_snap_threshold(1e-3);
+        check_random_walk_bracketing(|target| snapping.next(target), 0.0, SAMPLES);
+        let mut morphing = TinySmoother::default();
+        morphing.set_half_life_ramp(0.1, 48_000.0, SAMPLES as u32 / 2);
+        check_random_walk_bracketing(|target| morphing.next(target), 0.0, SAMPLES);
+        let mut f32_only = crate::TinySmootherF32::default();
+        check_random_walk_bracketing(|target| f32_only.next(target), 0.0, SAMPLES);
+    }
+
+    #[test]
+    #[ignore = "Simulates an hour at 48 kHz - run with cargo test -- --ignored"]
+    fn smoother_never_overshoots_a_random_walk_for_an_hour() {
+        const SAMPLES: usize = 48_000 * 3600;
+        let mut default = TinySmoother::default();
+        check_random_walk_bracketing(|target| default.next(target), 0.0, SAMPLES);
+        let mut f32_only = crate::TinySmootherF32::default();
+        check_random_walk_bracketing(|target| f32_only.next(target), 0.0, SAMPLES);
+    }
+
+    #[test]
+    fn smoother_does_not_drift_when_target_is_reached() {
+        let mut tiny_smoother = TinySmoother::default();
+        const TARGET: f32 = 1.0;
+        const SAMPLE_RATE: usize = 48_000;
+        const TEST_DURATION_MINUTES: usize = 15;
+
+        // wait until 99% of the target is reached.
+        let mut value = 0.0;
+        let samples_to_target_count = (0..)
+            .map(|_| tiny_smoother.next(TARGET))
+            .position(|value| value >= 0.99)
+            .unwrap();
+
+        println!(
+            "Target reached after {} samples ({:.1} ms at 48kHz)",
+            samples_to_target_count,
+            samples_to_target_count as f64 * 1000.0 / SAMPLE_RATE as f64
+        );
+
+        // Start time measurement.
+        let start = std::time::Instant::now();
+
+        // let it run for fifteen minutes and check every second for drift.
+        let mut max_drift = 0.0f32;
+        for second in 0..TEST_DURATION_MINUTES * 60 {
+            for _ in 0..SAMPLE_RATE {
+                value = tiny_smoother.next(TARGET);
+            }
+
+            // Drift-Check
+            let drift = (value - TARGET).abs();
+            max_drift = max_drift.max(drift);
+            assert!(
+                drift < 0.01,
+                "Drift detected after {} seconds: value={:.17}, drift={:e}",
+                second + 1,
+                value,
+                drift
+            );
+        }
+
+        // End time measurement.
+        let elapsed = start.elapsed();
+        let elapsed_micros = elapsed.as_micros();
+        let simulated_micros = (TEST_DURATION_MINUTES * 60 * 1_000_000) as u128;
+        let realtime_factor = simulated_micros as f64 / elapsed_micros as f64;
+
+        println!(
+            "Final value after {} minutes: {:.17}",
+            TEST_DURATION_MINUTES, value
+        );
+        println!("Maximum drift from target: {:e}", max_drift);
+        println!(
+            "Performance: {} minutes audio processed in {:.3} ms",
+            TEST_DURATION_MINUTES,
+            elapsed.as_secs_f64() * 1000.0
+        );
+        println!(
+            "Realtime factor: {:.0}x (could run ~{:.0} smoother in parallel)",
+            realtime_factor, realtime_factor
+        );
+    }
+
+    #[test]
+    fn smoother_can_be_reset() {
+        let mut smoother = TinySmoother::default();
+        // let it run for 500 samples
+        for _ in 0..500 {
+            smoother.next(1.0);
+        }
+        // now the value should be close to 0.5
+        assert!(smoother.next(1.0) > 0.499);
+
+        smoother.reset();
+        // after reset, the value should be close to 0.0
+        assert!(smoother.next(1.0) < 0.01);
+    }
+
+    #[test]
+    fn smoother_can_be_reset_to_a_value() {
+        let mut smoother = TinySmoother::default();
+        smoother.reset_to(0.75);
+        assert_eq!(smoother.next(0.75), 0.75);
+
+        // non-finite values are ignored
+        smoother.reset_to(f32::NAN);
+        assert_eq!(smoother.next(0.75), 0.75);
+
+        // reset() still returns to the start value
+        smoother.reset();
+        assert!(smoother.next(0.75) < 0.01);
+    }
+
+    #[test]
+    fn smoother_next_f64_agrees_with_next() {
+        let mut smoother = TinySmoother::default();
+        let mut smoother_f64 = TinySmoother::default();
+        for i in 0..5000 {
+            let target = if i < 2500 { 1.0 } else { -0.25 };
+            let value = smoother.next(target);
+            let value_f64 = smoother_f64.next_f64(target as f64);
+            assert!((value as f64 - value_f64).abs() <= f32::EPSILON as f64);
+            assert_eq!(smoother_f64.current_value_f64(), value_f64);
+        }
+    }
+
+    #[test]
+    fn smoother_next_f64_ignores_non_finite_targets() {
+        let mut smoother = TinySmoother::new(0.5, 0.0);
+        let last_valid = smoother.next_f64(1.0);
+        assert_eq!(smoother.next_f64(f64::NAN), last_valid);
+        assert_eq!(smoother.next_f64(f64::INFINITY), last_valid);
+    }
+
+    #[test]
+    fn smoother_next_branchless_agrees_with_next() {
+        let targets = [1.0, f32::NAN, 0.25, f32::INFINITY, f32::NEG_INFINITY, -0.5, 1e-30];
+        let configure = |smoother: &mut TinySmoother, case: usize| match case {
+            1 => smoother.set_snap_threshold(1e-3),
+            2 => smoother.set_half_life_ramp(1.0, 48_000.0, 100),
+            _ => {}
+        };
+        for (case, beta) in [0.0, 0.5, 0.999].into_iter().enumerate() {
+            let mut smoother = TinySmoother::new(beta, 0.0);
+            let mut branchless = TinySmoother::new(beta, 0.0);
+            configure(&mut smoother, case);
+            configure(&mut branchless, case);
+            for i in 0..2000 {
+                let target = targets[i / 50 % targets.len()];
+                assert_eq!(
+                    smoother.next(target).to_bits(),
+                    branchless.next_branchless(target).to_bits(),
+                    "beta = {beta}, sample {i}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn smoother_snaps_exactly_to_target() {
+        let mut smoother = TinySmoother::default();
+        smoother.set_snap_threshold(1e-4);
+
+        let samples_to_target = (0..)
+            .map(|_| smoother.next(1.0))
+            .position(|value| value == 1.0)
+            .unwrap();
+        assert!(samples_to_target < 10_000);
+
+        // and stays there
+        for _ in 0..48_000 {
+            assert_eq!(smoother.next(1.0), 1.0);
+        }
+        assert_eq!(smoother.current_value_f64(), 1.0);
+    }
+
+    #[test]
+    fn smoother_does_not_snap_by_default() {
+        let mut smoother = TinySmoother::default();
+        for _ in 0..5000 {
+            smoother.next(1.0);
+        }
+        assert!(smoother.current_value_f64() < 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Snap threshold must be finite and non-negative")]
+    fn smoother_panics_on_negative_snap_threshold() {
+        TinySmoother::default().set_snap_threshold(-1.0);
+    }
+
+    #[test]
+    fn smoother_tick_moves_toward_target() {
+        let mut smoother = TinySmoother::default();
+        assert!(!smoother.is_smoothing());
+
+        smoother.set_target(1.0);
+        assert!(smoother.is_smoothing());
+
+        let mut reference = TinySmoother::default();
+        for _ in 0..1000 {
+            assert_eq!(smoother.tick(), reference.next(1.0));
+        }
+    }
+
+    #[test]
+    fn smoother_set_target_ignores_non_finite_values() {
+        let mut smoother = TinySmoother::default();
+        smoother.set_target(f32::NAN);
+        smoother.set_target(f32::INFINITY);
+        assert!(!smoother.is_smoothing());
+        assert_eq!(smoother.tick(), 0.0);
+    }
+
+    #[test]
+    fn smoother_stops_smoothing_after_snapping() {
+        let mut smoother = TinySmoother::default();
+        smoother.set_snap_threshold(1e-4);
+        smoother.set_target(1.0);
+        while smoother.is_smoothing() {
+            smoother.tick();
+        }
+        assert_eq!(smoother.tick(), 1.0);
+    }
+
+    #[test]
+    fn smoother_reset_clears_pending_target() {
+        let mut smoother = TinySmoother::new(0.99, 0.25);
+        smoother.set_target(1.0);
+        for _ in 0..100 {
+            smoother.tick();
+        }
+
+        smoother.reset();
+        assert!(!smoother.is_smoothing());
+        for _ in 0..1000 {
+            assert_eq!(smoother.tick(), 0.25);
+        }
+
+        // a new target starts a new ramp
+        smoother.set_target(1.0);
+        assert!(smoother.tick() > 0.25);
+    }
+
+    #[test]
+    fn next_with_changed_reports_movement_until_settled() {
+        let mut smoother = TinySmoother::new(beta_from_half_life(10.0), 0.0);
+        for n in 0..100 {
+            let (value, changed) = smoother.next_with_changed(1.0);
+            assert!(changed, "sample {n}: {value}");
+        }
+        // the first unchanged output is only a rounding step away from the target
+        let (value, _) = (0..10_000)
+            .map(|_| smoother.next_with_changed(1.0))
+            .find(|&(_, changed)| !changed)
+            .unwrap();
+        assert!((value - 1.0).abs() < 1e-6, "{value}");
+
+        smoother.settle(1.0);
+        assert_eq!(smoother.next_with_changed(1.0), (1.0, false));
+    }
+
+    #[test]
+    fn next_with_changed_ignores_steps_below_the_snap_threshold() {
+        let mut smoother = TinySmoother::new(0.9, 0.0);
+        smoother.set_snap_threshold(0.01);
+        assert!(smoother.next_with_changed(1.0).1);
+        // at 0.9^n, steps shrink below 0.01 once the remaining error is below 0.1
+        let (value, changed) = (0..100)
+            .map(|_| smoother.next_with_changed(1.0))
+            .find(|&(_, changed)| !changed)
+            .unwrap();
+        assert!(!changed && value > 0.89, "{value}");
+    }
+
+    #[test]
+    fn from_cutoff_hz_is_3_db_down_at_the_cutoff() {
+        const SAMPLE_RATE: f32 = 48_000.0;
+        for cutoff in [10.0, 100.0, 1000.0] {
+            let mut smoother = TinySmoother::from_cutoff_hz(SAMPLE_RATE, cutoff, 0.0);
+            let sine = |n: usize| (std::f32::consts::TAU * cutoff * n as f32 / SAMPLE_RATE).sin();
+
+            // let the transient decay, then measure the steady-state amplitude
+            let settle = SAMPLE_RATE as usize;
+            for n in 0..settle {
+                smoother.next(sine(n));
+            }
+            let amplitude = (settle..settle + SAMPLE_RATE as usize)
+                .map(|n| smoother.next(sine(n)).abs())
+                .fold(0.0f32, f32::max);
+            let db = 20.0 * amplitude.log10();
+            assert!((db + 3.01).abs() < 0.1, "cutoff {cutoff} Hz: {db} dB");
+        }
+    }
+
+    #[test]
+    fn from_cutoff_hz_at_or_above_nyquist_disables_smoothing() {
+        for cutoff in [24_000.0, 30_000.0] {
+            let mut smoother = TinySmoother::from_cutoff_hz(48_000.0, cutoff, 0.0);
+            assert_eq!(smoother.beta(), 0.0);
+            assert_eq!(smoother.next(0.75), 0.75);
+        }
+        assert!(TinySmoother::from_cutoff_hz(48_000.0, 23_999.0, 0.0).beta() > 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cutoff must be finite and positive, got 0")]
+    fn from_cutoff_hz_rejects_zero_cutoff() {
+        TinySmoother::from_cutoff_hz(48_000.0, 0.0, 0.0);
+    }
+
+    #[test]
+    fn next_reciprocal_stays_finite_on_a_ramp_to_a_small_gain() {
+        let mut gain = TinySmoother::new(beta_from_half_life(100.0), 1.0);
+        let mut last = 1.0;
+        for n in 0..10_000 {
+            let reciprocal = gain.next_reciprocal(1e-7);
+            assert!(reciprocal.is_finite() && reciprocal >= last, "sample {n}: {reciprocal}");
+            last = reciprocal;
+        }
+        assert_eq!(last, 1.0 / MIN_GAIN);
+
+        // the reciprocal of the smoothed gain, not a smoothed reciprocal
+        let mut gain = TinySmoother::new(0.5, 1.0);
+        assert_eq!(gain.next_reciprocal(0.25), 1.0 / 0.625);
+    }
+
+    #[test]
+    fn next_reciprocal_guards_zero_and_keeps_the_sign() {
+        let mut gain = TinySmoother::new(0.0, 1.0);
+        assert_eq!(gain.next_reciprocal(0.0), 1.0 / MIN_GAIN);
+        assert_eq!(gain.next_reciprocal(-0.5), -2.0);
+        assert_eq!(gain.next_reciprocal(f32::NAN), -2.0);
+    }
+
+    #[test]
+    fn apply_and_peak_matches_apply_smoothed_gain_and_peak_db() {
+        use rand::{Rng, SeedableRng, rngs::SmallRng};
+
+        let mut rng = SmallRng::seed_from_u64(0xA991_7EA7);
+        let mut fused = TinySmoother::default();
+        let mut separate = TinySmoother::default();
+        for block in 0..100 {
+            let target = rng.random_range(0.0..2.0);
+            let mut buffer: Vec<f32> = (0..256).map(|_| rng.random_range(-1.0..1.0)).collect();
+            let mut expected = buffer.clone();
+
+            let peak = fused.apply_and_peak(target, &mut buffer, 2);
+            crate::apply_smoothed_gain(&mut separate, target, &mut expected, 2);
+            assert_eq!(buffer, expected, "block {block}");
+            assert_eq!(peak, crate::peak_db(&expected), "block {block}");
+        }
+    }
+
+    #[test]
+    fn apply_and_peak_reports_silence_and_ignores_non_finite_samples() {
+        let mut gain = TinySmoother::new(0.0, 1.0);
+        assert_eq!(gain.apply_and_peak(1.0, &mut [], 2), -100.0);
+        assert_eq!(gain.apply_and_peak(1.0, &mut [0.0; 8], 2), -100.0);
+        let mut buffer = [f32::INFINITY, 0.1, f32::NAN, -0.1];
+        assert_eq!(gain.apply_and_peak(1.0, &mut buffer, 2), -20.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Buffer length 3 is not a multiple of the channel count 2")]
+    fn apply_and_peak_rejects_partial_frames() {
+        TinySmoother::default().apply_and_peak(1.0, &mut [0.0; 3], 2);
+    }
+
+    #[test]
+    fn default_smoother_reports_beta_and_start_value() {
+        let smoother = TinySmoother::default();
+        assert_eq!(smoother.beta(), (-(2.0_f64.ln()) / 500.0).exp());
+        assert_eq!(smoother.start_value(), 0.0);
+
+        let smoother = TinySmoother::new(0.75, -0.5);
+        assert_eq!(smoother.beta(), 0.75);
+        assert_eq!(smoother.start_value(), -0.5);
+    }
+
+    #[test]
+    fn default_smoother_reports_500_samples_half_life() {
+        let smoother = TinySmoother::default();
+        assert!((smoother.half_life_samples() - 500.0).abs() < 1e-6);
+        assert!((smoother.half_life_ms(48_000.0) - 10.416_666).abs() < 1e-3);
+    }
+
+    #[test]
+    fn smoother_samples_to_reach_half_is_half_life() {
+        let smoother = TinySmoother::default();
+        assert!((smoother.samples_to_reach(0.0, 1.0, 0.5) - 500.0).abs() < 1e-9);
+        assert!((smoother.samples_to_reach(3.0, -1.0, 0.5) - 500.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn smoother_samples_to_reach_matches_simulation() {
+        let mut smoother = TinySmoother::new(0.99, 0.0);
+        let expected = smoother.samples_to_reach(0.0, 2.0, 0.75);
+        let crossing = (1..).find(|_| smoother.next(2.0) >= 1.5).unwrap();
+        assert_eq!(crossing, expected.ceil() as usize);
+    }
+
+    #[test]
+    fn smoother_samples_to_reach_handles_edge_cases() {
+        let smoother = TinySmoother::default();
+        assert_eq!(smoother.samples_to_reach(0.0, 1.0, 0.0), 0.0);
+        assert_eq!(smoother.samples_to_reach(0.0, 1.0, f32::NAN), 0.0);
+        assert_eq!(smoother.samples_to_reach(0.0, 1.0, 1.0), f64::INFINITY);
+        assert_eq!(smoother.samples_to_reach(1.0, 1.0, 0.5), 0.0);
+        assert_eq!(TinySmoother::new(0.0, 0.0).samples_to_reach(0.0, 1.0, 0.5), 0.0);
+    }
+
+    #[test]
+    fn smoother_reports_time_constant() {
+        let smoother = TinySmoother::new((-1.0_f64 / 480.0).exp(), 0.0);
+        assert!((smoother.time_constant_samples() - 480.0).abs() < 1e-6);
+        assert!((smoother.time_constant_ms(48_000.0) - 10.0).abs() < 1e-6);
+        // the half-life is ln(2) times the time constant
+        assert!((smoother.half_life_samples() - 480.0 * 2.0_f64.ln()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn smoother_without_smoothing_reports_zero_times() {
+        let smoother = TinySmoother::new(0.0, 0.0);
+        assert_eq!(smoother.half_life_samples(), 0.0);
+        assert_eq!(smoother.time_constant_samples(), 0.0);
+        assert_eq!(smoother.half_life_ms(48_000.0), 0.0);
+        assert_eq!(smoother.time_constant_ms(48_000.0), 0.0);
+    }
+
+    #[test]
+    fn smoother_skip_matches_sequential_next() {
+        for n in [1u32, 2, 10, 499, 500, 4321, 48_000] {
+            let mut skipped = TinySmoother::new(0.999, 0.25);
+            let mut stepped = TinySmoother::new(0.999, 0.25);
+
+            let value = skipped.skip(-1.5, n);
+            let mut expected = 0.0;
+            for _ in 0..n {
+                expected = stepped.next(-1.5);
+            }
+            assert!((value - expected).abs() <= f32::EPSILON * 2.0, "n = {n}");
+            // both continue identically
+            assert!((skipped.next(-1.5) - stepped.next(-1.5)).abs() <= f32::EPSILON * 2.0);
+        }
+    }
+
+    #[test]
+    fn smoother_skip_handles_edge_cases() {
+        let mut smoother = TinySmoother::new(0.9, 0.5);
+        assert_eq!(smoother.skip(1.0, 0), 0.5);
+        assert_eq!(smoother.skip(f32::NAN, 100), 0.5);
+
+        let mut instant = TinySmoother::new(0.0, 0.0);
+        assert_eq!(instant.skip(1.0, 3), 1.0);
+    }
+
+    #[test]
+    fn smoother_half_life_ramp_reaches_target_beta() {
+        let mut smoother = TinySmoother::default();
+        let from_beta = smoother.beta;
+        let to_beta = beta_from_half_life(48.0);
+
+        smoother.set_half_life_ramp(1.0, 48_000.0, 1000);
+        for _ in 0..500 {
+            smoother.next(1.0);
+        }
+        // halfway in ln(beta) is the geometric mean
+        assert!((smoother.beta - (from_beta * to_beta).sqrt()).abs() < 1e-12);
+
+        for _ in 0..499 {
+            smoother.next(1.0);
+        }
+        assert_ne!(smoother.beta, to_beta);
+        smoother.next(1.0);
+        assert_eq!(smoother.beta, to_beta);
+        assert!((smoother.half_life_samples() - 48.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn smoother_half_life_ramp_keeps_output_continuous() {
+        let mut smoother = TinySmoother::default();
+        smoother.set_half_life_ramp(0.5, 48_000.0, 2000);
+
+        let mut last = smoother.next(1.0);
+        for _ in 0..4000 {
+            let value = smoother.next(1.0);
+            // monotonic, and no step larger than the fastest smoothing allows
+            assert!(value >= last);
+            assert!(value - last <= (1.0 - last) * 0.03 + 1e-6);
+            last = value;
+        }
+    }
+
+    #[test]
+    fn smoother_half_life_ramp_with_zero_length_applies_at_once() {
+        let mut smoother = TinySmoother::default();
+        smoother.set_half_life_ramp(0.0, 48_000.0, 0);
+        assert_eq!(smoother.next(1.0), 1.0);
+    }
+
+    #[test]
+    fn smoother_next_clamped_caps_output_and_state() {
+        let mut smoother = TinySmoother::default();
+        for _ in 0..10_000 {
+            assert!(smoother.next_clamped(2.0, 0.0, 1.0) <= 1.0);
+        }
+        assert_eq!(smoother.current_value_f64(), 1.0);
+
+        // back inside the range: smoothing resumes from the boundary without a jump
+        let first = smoother.next_clamped(0.5, 0.0, 1.0);
+        assert!(first < 1.0 && first > 0.999);
+        let mut last = first;
+        for _ in 0..1000 {
+            let value = smoother.next_clamped(0.5, 0.0, 1.0);
+            assert!(value < last && last - value < 0.001);
+            last = value;
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Invalid range")]
+    fn smoother_next_clamped_panics_on_inverted_range_in_debug_builds() {
+        TinySmoother::default().next_clamped(0.5, 1.0, 0.0);
+    }
+
+    #[test]
+    #[cfg(not(debug_assertions))]
+    fn smoother_next_clamped_sanitizes_invalid_ranges() {
+        let mut smoother = TinySmoother::new(0.0, 0.0);
+        // inverted bounds are swapped
+        assert_eq!(smoother.next_clamped(2.0, 1.0, 0.0), 1.0);
+        // a NaN bound is ignored
+        assert_eq!(smoother.next_clamped(2.0, 0.0, f32::NAN), 2.0);
+        assert_eq!(smoother.next_clamped(-2.0, f32::NAN, 1.0), -2.0);
+        assert_eq!(smoother.next_clamped(-2.0, f32::NAN, f32::NAN), -2.0);
+    }
+
+    #[test]
+    fn smoother_next_mapped_follows_mapped_trajectory() {
+        let to_hz = |norm: f32| 20.0 * 1000.0_f32.powf(norm);
+        let mut smoother = TinySmoother::new(0.9, 20.0);
+        let mut normalized = TinySmoother::new(0.9, 0.0);
+
+        for n in 1..=50 {
+            // exponential approach in Hz, not in the normalized domain
+            let hz = smoother.next_mapped(1.0, to_hz) as f64;
+            let expected = 20_000.0 - 19_980.0 * 0.9_f64.powi(n);
+            assert!((hz / expected - 1.0).abs() < 1e-5, "n = {n}");
+
+            // mapping the smoothed normalized value would lag behind
+            let warped = to_hz(normalized.next(1.0)) as f64;
+            assert!(hz > warped, "n = {n}");
+        }
+    }
+
+    #[test]
+    fn smoother_blend_returns_inputs_at_the_ends() {
+        let (a, b) = (0.3f32, -0.8f32);
+        let mut dry = TinySmoother::new(0.99, 0.0);
+        let mut wet = TinySmoother::new(0.99, 1.0);
+        assert_eq!(dry.blend(a, b, 0.0), a);
+        assert_eq!(wet.blend(a, b, 1.0), b);
+        // out of range controls are clamped
+        assert_eq!(dry.blend(a, b, -3.0), a);
+        assert_eq!(wet.blend(a, b, 7.0), b);
+
+        let mut dry = TinySmoother::new(0.99, 0.0);
+        let mut wet = TinySmoother::new(0.99, 1.0);
+        assert_eq!(dry.blend_equal_power(a, b, 0.0), a);
+        assert_eq!(wet.blend_equal_power(a, b, 1.0), b);
+    }
+
+    #[test]
+    fn smoother_blend_ramps_without_clicks() {
+        let beta = beta_from_half_life(48.0);
+        let mut linear = TinySmoother::new(beta, 0.0);
+        let mut equal_power = TinySmoother::new(beta, 0.0);
+        let (a, b) = (1.0f32, -1.0f32);
+        let (mut last_linear, mut last_equal_power) = (a, a);
+
+        // jump to fully wet, then sweep back to dry and hold
+        let sweep = (0..4800).map(|n| 1.0 - n as f32 / 4800.0);
+        let controls = std::iter::repeat_n(1.0, 4800).chain(sweep).chain([0.0; 4800]);
+        for control in controls {
+            let value = linear.blend(a, b, control);
+            assert!((value - last_linear).abs() < 0.03);
+            last_linear = value;
+
+            let value = equal_power.blend_equal_power(a, b, control);
+            assert!((value - last_equal_power).abs() < 0.05);
+            last_equal_power = value;
+        }
+        assert!((last_linear - a).abs() < 1e-6);
+
+        // NaN keeps the mix
+        assert_eq!(linear.blend(a, b, f32::NAN), last_linear);
+    }
+
+    #[test]
+    fn smoother_current_db_approaches_zero_during_a_ramp_to_unity() {
+        let mut smoother = TinySmoother::default();
+        assert_eq!(smoother.current_db(), f32::NEG_INFINITY);
+
+        let mut last_db = f32::NEG_INFINITY;
+        for n in 1..=5_000 {
+            smoother.next(1.0);
+            let db = smoother.current_db();
+            assert!(db < 0.0 && db >= last_db, "n = {n}: {db} dB");
+            last_db = db;
+        }
+        // ten half-lives: 1 - 2^-10
+        assert!((last_db - volt_to_db_f(1.0 - 1.0 / 1024.0)).abs() < 1e-3);
+        assert!(last_db > -0.01);
+
+        smoother.settle(1.0);
+        assert_eq!(smoother.current_db(), 0.0);
+    }
+
+    #[test]
+    fn smoother_prime_warms_up_toward_the_target() {
+        let mut smoother = TinySmoother::new(beta_from_half_life(48.0), 0.0);
+        smoother.prime(1.0, 1000);
+        // ~21 half-lives
+        assert!((smoother.next(1.0) - 1.0).abs() < 1e-6);
+
+        // same state as skip()
+        let mut primed = TinySmoother::default();
+        let mut skipped = TinySmoother::default();
+        primed.prime(0.5, 300);
+        skipped.skip(0.5, 300);
+        assert_eq!(primed.next(0.5), skipped.next(0.5));
+
+        // non-finite targets are ignored
+        primed.prime(f32::NAN, 1000);
+        assert_eq!(primed.current_value_f64(), skipped.current_value_f64());
+    }
+
+    #[test]
+    fn smoother_settle_runs_to_the_target() {
+        let mut smoother = TinySmoother::default();
+        // 25 half-lives: the distance drops below half an f32 ulp of 1.0
+        let samples = smoother.settle(1.0);
+        assert!((12_500..=12_501).contains(&samples), "samples = {samples}");
+        assert!(!smoother.is_smoothing());
+        assert_eq!(smoother.current_value_f64(), 1.0);
+        assert_eq!(smoother.next(1.0), 1.0);
+
+        // already settled
+        assert_eq!(smoother.settle(1.0), 0);
+        // non-finite targets settle toward the current target
+        assert_eq!(smoother.settle(f32::NAN), 0);
+    }
+
+    #[test]
+    fn smoother_settle_stops_at_the_snap_threshold() {
+        let mut smoother = TinySmoother::default();
+        smoother.set_snap_threshold(1e-3);
+        let expected = smoother.samples_to_reach(0.0, 1.0, 0.999).ceil() as u32;
+        assert_eq!(smoother.settle(1.0), expected);
+        assert_eq!(smoother.next(1.0), 1.0);
+    }
+
+    #[test]
+    fn smoother_settle_is_capped() {
+        let mut smoother = TinySmoother::new(beta_from_half_life(1e9), 0.0);
+        assert_eq!(smoother.settle(1.0), MAX_SETTLE_SAMPLES);
+        assert_eq!(smoother.next(1.0), 1.0);
+    }
+
+    #[test]
+    fn smoother_reset_and_set_target_ramps_from_start() {
+        let mut smoother = TinySmoother::default();
+        smoother.reset_to(0.7);
+        smoother.reset_and_set_target(1.0);
+
+        let first = smoother.tick();
+        assert!(first > 0.0 && first < 0.01);
+        let mut last = first;
+        for _ in 0..2000 {
+            let value = smoother.tick();
+            assert!(value > last);
+            last = value;
+        }
+        assert!(last > 0.9);
+
+        // a non-finite target leaves the smoother at rest at the start value
+        smoother.reset_and_set_target(f32::NAN);
+        assert!(!smoother.is_smoothing());
+        assert_eq!(smoother.tick(), 0.0);
+    }
+
+    #[test]
+    fn smoother_begin_release_fades_to_silence() {
+        let mut smoother = TinySmoother::default();
+        smoother.reset_to(-0.8);
+        assert!(!smoother.is_silent());
+
+        smoother.begin_release();
+        let mut last = 0.8;
+        let samples = (1..)
+            .find(|_| {
+                let value = smoother.tick().abs();
+                assert!(value < last);
+                last = value;
+                smoother.is_silent()
+            })
+            .unwrap();
+        // 0.8 to 1e-5 takes about 16 half-lives
+        assert!((8000..8200).contains(&samples), "samples = {samples}");
+    }
+
+    #[test]
+    fn smoother_set_half_life_samples_changes_speed_in_place() {
+        let mut smoother = TinySmoother::default();
+        smoother.reset_to(0.25);
+        smoother.set_half_life_samples(250.0);
+        assert_eq!(smoother.current_value_f64(), 0.25);
+
+        smoother.reset_to(0.0);
+        let crossing = (1..).find(|_| smoother.next(1.0) >= 0.5).unwrap();
+        assert_eq!(crossing, 250);
     }
-}
 
-impl TinySmoother {
-    /// Creates a smoother with a custom smoothing coefficient.
-    ///
-    /// # Parameters
-    /// * `beta` - Smoothing coefficient in range (0.0, 1.0)
-    ///   - Values near 0.0 give instant response (no smoothing)
-    ///   - Values near 1.0 give slower smoothing
-    ///   - Values equal to or greater than 1.0 are unstable
-    ///
-    /// To calculate beta for a specific time constant:
-    /// * For 50% at n samples: `beta = e^(-ln(2)/n)`
-    /// * For 63.2% at n samples: `beta = e^(-1/n)`
-    ///
-    /// * `start_value` - the value the smoother should start from when reset (usually 0.0 or 1.0)
-    /// 
-    /// # Panics
-    ///
-    /// Panics if `beta` is not in range [0.0, 1.0) or if `start_value` is not finite.
-    /// 
-    pub fn new(beta: f64, start_value: f32) -> TinySmoother {
-        assert!(
-            beta >= 0.0 && beta < 1.0,
-            "Beta must be in range [0.0, 1.0), got {}",
-            beta
-        );
-        assert!(
-            start_value.is_finite(),
-            "Start value must be finite, got {}",
-            start_value
-        );
-        TinySmoother {
-            last_value: start_value as f64,
-            beta,
-            start_value,
+    #[test]
+    fn smoother_set_half_life_samples_handles_edge_cases() {
+        let mut smoother = TinySmoother::default();
+        smoother.set_half_life_samples(f64::NAN);
+        assert!((smoother.half_life_samples() - 500.0).abs() < 1e-6);
+
+        smoother.set_half_life_samples(f64::INFINITY);
+        assert!(smoother.beta < 1.0);
+
+        for instant in [0.0, -5.0, f64::NEG_INFINITY] {
+            smoother.set_half_life_samples(instant);
+            assert_eq!(smoother.next(0.75), 0.75);
         }
     }
 
-    /// Processes the next target value with exponential smoothing.
-    ///
-    /// The filter converges smoothly toward the target using an error-feedback
-    /// approach that guarantees numerical stability. Once the target is reached,
-    /// the output remains exactly at the target value without drift.
-    ///
-    /// # Example
-    /// ```
-    /// use audio_utils::TinySmoother;
-    ///
-    /// let mut smoother = TinySmoother::default();
-    /// let smoothed = smoother.next(1.0);  // Start transition to 1.0
-    /// ```
-    pub fn next(&mut self, target: f32) -> f32 {
-        if !target.is_finite() {
-            return self.last_value as f32;
+    #[test]
+    fn smoother_with_initial_target_does_not_ramp() {
+        let mut smoother = TinySmoother::with_initial_target(beta_from_half_life(500.0), 0.0, 0.8);
+        assert!(!smoother.is_smoothing());
+        for _ in 0..10 {
+            assert_eq!(smoother.next(0.8), 0.8);
         }
-        let target = target as f64;
-        let new_value = target - self.beta * (target - self.last_value);
-        self.last_value = new_value;
-        new_value as f32
+
+        // reset still returns to the start value
+        smoother.reset();
+        assert_eq!(smoother.current_value_f64(), 0.0);
     }
-    /// Resets the smoother to its starting value.
-    ///
-    /// The starting value is determined at creation time:
-    /// - Via `TinySmoother::new()` where it's explicitly specified
-    /// - Via `TinySmoother::default()` where it defaults to 0.0
-    ///
-    /// # Example
-    /// ```
-    /// use audio_utils::TinySmoother;
-    ///
-    /// let mut smoother = TinySmoother::default();
-    /// // Run for 500 samples
-    /// for _ in 0..500 {
-    ///     smoother.next(1.0);
-    /// }
-    /// // Now the value should be close to 0.5
-    /// assert!(smoother.next(1.0) > 0.499);
-    ///
-    /// smoother.reset();
-    /// // After reset, the value should be close to 0.0
-    /// assert!(smoother.next(1.0) < 0.01);
-    /// ```
-    pub fn reset(&mut self) {
-        self.last_value = self.start_value as f64;
+
+    #[test]
+    #[should_panic(expected = "Initial target must be finite")]
+    fn smoother_with_initial_target_panics_on_non_finite_target() {
+        TinySmoother::with_initial_target(0.5, 0.0, f32::NAN);
     }
-}
 
-//--- Tests ---------------------------------------------------------------------------------------
-//
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn smoother_jump_if_far_keeps_small_jumps_smooth() {
+        let mut smoother = TinySmoother::default();
+        let mut reference = TinySmoother::default();
+        for _ in 0..1000 {
+            assert_eq!(smoother.jump_if_far(0.1, 0.25), reference.next(0.1));
+        }
+    }
 
-    // smoother -------------
     #[test]
-    fn smoother_reaches_half_target_within_500_samples() {
-        let mut tiny_smoother = TinySmoother::default();
-        // start at 0.0
-        let start = tiny_smoother.next(0.0);
-        assert_eq!(start, 0.0);
+    fn smoother_jump_if_far_snaps_large_jumps() {
+        // -40 dB to 0 dB
+        let mut smoother = TinySmoother::new(0.999, 0.01);
+        let first = smoother.jump_if_far(1.0, 0.1);
+        assert!(first > 0.9 && first < 0.9002);
 
-        // target 1.0 for 500 samples
-        let target = 1.0f32;
-        for _ in 0..500 {
-            let _value = tiny_smoother.next(target);
+        // downward jumps snap from the other side
+        let first = smoother.jump_if_far(0.0, 0.1);
+        assert!(first < 0.1 && first > 0.0998);
+
+        // non-finite targets are ignored
+        assert_eq!(smoother.jump_if_far(f32::NAN, 0.1), first);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Threshold must be non-negative")]
+    fn smoother_jump_if_far_panics_on_negative_threshold_in_debug_builds() {
+        TinySmoother::default().jump_if_far(0.5, -1.0);
+    }
+
+    #[test]
+    #[cfg(not(debug_assertions))]
+    fn smoother_jump_if_far_treats_invalid_thresholds_as_zero() {
+        for threshold in [-1.0, f32::NAN] {
+            let mut smoother = TinySmoother::new(0.5, 0.0);
+            assert_eq!(smoother.jump_if_far(0.5, threshold), 0.5);
         }
+    }
 
-        // now the value should be close to 0.5
-        let value = tiny_smoother.next(target);
-        assert!(value > 0.499 && value < 0.501);
-        println!("value = {value}.")
+    #[test]
+    fn smoother_process_frames_applies_one_gain_per_frame() {
+        let mut smoother = TinySmoother::default();
+        let mut left = [1.0f32; 64];
+        let mut right = [-2.0f32; 64];
+        smoother.process_frames(1.0, &mut [&mut left, &mut right]);
+
+        let mut reference = TinySmoother::default();
+        for (&l, &r) in left.iter().zip(&right) {
+            let gain = reference.next(1.0);
+            assert_eq!(l, gain);
+            assert_eq!(r, -2.0 * gain);
+        }
+        assert!(left[0] < left[63]);
+        assert_eq!(smoother.current_value_f64(), reference.current_value_f64());
     }
 
     #[test]
-    fn smoother_does_not_drift_when_target_is_reached() {
-        let mut tiny_smoother = TinySmoother::default();
-        const TARGET: f32 = 1.0;
-        const SAMPLE_RATE: usize = 48_000;
-        const TEST_DURATION_MINUTES: usize = 15;
+    #[should_panic(expected = "All channels must have the same length")]
+    fn smoother_process_frames_panics_on_mismatched_channels() {
+        let mut left = [1.0f32; 64];
+        let mut right = [1.0f32; 32];
+        TinySmoother::default().process_frames(1.0, &mut [&mut left, &mut right]);
+    }
 
-        // wait until 99% of the target is reached.
-        let mut value = 0.0;
-        let samples_to_target_count = (0..)
-            .map(|_| tiny_smoother.next(TARGET))
-            .position(|value| value >= 0.99)
-            .unwrap();
+    #[test]
+    fn smoother_smooth_iter_matches_next() {
+        let targets: Vec<f32> = (0..1000).map(|n| (n / 100) as f32 * 0.1).collect();
 
-        println!(
-            "Target reached after {} samples ({:.1} ms at 48kHz)",
-            samples_to_target_count,
-            samples_to_target_count as f64 * 1000.0 / SAMPLE_RATE as f64
-        );
+        let mut iterated = TinySmoother::default();
+        let from_iter: Vec<f32> = iterated.smooth_iter(&targets).collect();
 
-        // Start time measurement.
-        let start = std::time::Instant::now();
+        let mut stepped = TinySmoother::default();
+        let from_next: Vec<f32> = targets.iter().map(|&target| stepped.next(target)).collect();
 
-        // let it run for fifteen minutes and check every second for drift.
-        let mut max_drift = 0.0f32;
-        for second in 0..TEST_DURATION_MINUTES * 60 {
-            for _ in 0..SAMPLE_RATE {
-                value = tiny_smoother.next(TARGET);
-            }
+        assert_eq!(from_iter, from_next);
+        assert_eq!(iterated.current_value_f64(), stepped.current_value_f64());
+    }
 
-            // Drift-Check
-            let drift = (value - TARGET).abs();
-            max_drift = max_drift.max(drift);
-            assert!(
-                drift < 0.01,
-                "Drift detected after {} seconds: value={:.17}, drift={:e}",
-                second + 1,
-                value,
-                drift
-            );
+    #[test]
+    fn smoother_reset_soft_ramps_to_start_value() {
+        let mut smoother = TinySmoother::default();
+        smoother.reset_to(1.0);
+        smoother.reset_soft();
+        assert_eq!(smoother.current_value_f64(), 1.0);
+
+        let mut last = 1.0;
+        for _ in 0..499 {
+            let value = smoother.tick();
+            assert!(value < last && last - value < 0.002);
+            last = value;
         }
+        // one half-life after the soft reset, half of the way is done
+        let value = smoother.tick();
+        assert!((value - 0.5).abs() < 1e-3);
+    }
 
-        // End time measurement.
-        let elapsed = start.elapsed();
-        let elapsed_micros = elapsed.as_micros();
-        let simulated_micros = (TEST_DURATION_MINUTES * 60 * 1_000_000) as u128;
-        let realtime_factor = simulated_micros as f64 / elapsed_micros as f64;
+    #[test]
+    fn smoother_set_target_db_drives_toward_linear_gain() {
+        let mut smoother = TinySmoother::new(0.9, 1.0);
+        smoother.set_target_db(-6.0);
+        let mut last = 1.0;
+        for _ in 0..1000 {
+            let value = smoother.tick();
+            assert!(value <= last);
+            last = value;
+        }
+        assert!((last - 0.501).abs() < 1e-3);
+        assert_eq!(last, db_to_volt_f(-6.0));
+    }
 
-        println!(
-            "Final value after {} minutes: {:.17}",
-            TEST_DURATION_MINUTES, value
-        );
-        println!("Maximum drift from target: {:e}", max_drift);
-        println!(
-            "Performance: {} minutes audio processed in {:.3} ms",
-            TEST_DURATION_MINUTES,
-            elapsed.as_secs_f64() * 1000.0
-        );
-        println!(
-            "Realtime factor: {:.0}x (could run ~{:.0} smoother in parallel)",
-            realtime_factor, realtime_factor
-        );
+    #[test]
+    fn smoother_set_target_db_handles_non_finite_values() {
+        let mut smoother = TinySmoother::new(0.0, 1.0);
+        smoother.set_target_db(f32::NAN);
+        assert_eq!(smoother.tick(), 1.0);
+        smoother.set_target_db(f32::NEG_INFINITY);
+        assert_eq!(smoother.tick(), 0.0);
     }
 
     #[test]
-    fn smoother_can_be_reset() {
-        let mut smoother = TinySmoother::default();
-        // let it run for 500 samples
-        for _ in 0..500 {
-            smoother.next(1.0);
+    fn absurdly_long_half_lives_yield_stable_smoothers() {
+        for half_life_samples in [1e9, 1e16, 1e20, f64::MAX] {
+            let smoother = TinySmoother::builder()
+                .half_life_samples(half_life_samples)
+                .build()
+                .unwrap();
+            assert!(smoother.beta < 1.0 && smoother.beta > 0.999_999_999);
         }
-        // now the value should be close to 0.5
-        assert!(smoother.next(1.0) > 0.499);
+        let beta = beta_from_half_life(TinySmoother::max_stable_half_life_samples());
+        assert_eq!(beta, 1.0 - f64::EPSILON / 2.0);
+    }
 
+    #[test]
+    fn absurdly_long_half_life_still_moves_toward_target() {
+        let mut smoother = TinySmoother::builder().half_life_samples(1e9).build().unwrap();
+        let value = smoother.next_f64(1.0);
+        assert!(value > 0.0 && value < 1e-9);
+    }
+
+    //--- StepResponse
+    #[test]
+    fn step_response_matches_reset_smoother() {
+        let beta = beta_from_half_life(37.0);
+        let mut smoother = TinySmoother::new(beta, 0.25);
+        smoother.next(0.9);
         smoother.reset();
-        // after reset, the value should be close to 0.0
-        assert!(smoother.next(1.0) < 0.01);
+
+        for (n, value) in StepResponse::new(beta, 0.25, -1.5).take(1000).enumerate() {
+            assert_eq!(value, smoother.next(-1.5), "n = {n}");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Target must be finite")]
+    fn step_response_panics_on_non_finite_target() {
+        StepResponse::new(0.5, 0.0, f32::NAN);
+    }
+
+    //--- SmoothingCoeffs
+    #[test]
+    fn smoothers_from_same_coeffs_behave_identically() {
+        let coeffs = SmoothingCoeffs::from_half_life_ms(48_000.0, 5.0, 0.25);
+        let mut a = TinySmoother::with_coeffs(&coeffs);
+        let mut b = TinySmoother::default();
+        b.apply_coeffs(&coeffs);
+        b.reset();
+
+        for n in 0..2000 {
+            let target = if n < 1000 { 1.0 } else { -0.5 };
+            assert_eq!(a.next(target), b.next(target));
+        }
+        assert!((a.half_life_samples() - 240.0).abs() < 1e-9);
+        assert_eq!(a.half_life_samples(), coeffs.half_life_samples());
+        assert_eq!(a.time_constant_samples(), coeffs.time_constant_samples());
+    }
+
+    #[test]
+    fn apply_coeffs_keeps_the_current_value() {
+        let mut smoother = TinySmoother::default();
+        smoother.reset_to(0.7);
+        smoother.apply_coeffs(&SmoothingCoeffs::new(0.5, 0.0));
+        assert_eq!(smoother.current_value_f64() as f32, 0.7);
+        assert_eq!(smoother.next(1.0), 0.85);
+    }
+
+    //--- builder
+    #[test]
+    fn builder_reproduces_default_configuration() {
+        let built = TinySmoother::builder().build().unwrap();
+        let default = TinySmoother::default();
+        assert_eq!(built.beta, default.beta);
+        assert_eq!(built.start_value, default.start_value);
+        assert_eq!(built.snap_threshold, default.snap_threshold);
+    }
+
+    #[test]
+    fn builder_converts_half_life_ms() {
+        let smoother = TinySmoother::builder()
+            .sample_rate(48_000.0)
+            .half_life_ms(10.0)
+            .start_value(1.0)
+            .snap_threshold(1e-4)
+            .build()
+            .unwrap();
+        assert!((smoother.half_life_samples() - 480.0).abs() < 1e-6);
+        assert_eq!(smoother.start_value, 1.0);
+        assert_eq!(smoother.snap_threshold, 1e-4_f32 as f64);
+    }
+
+    #[test]
+    fn builder_reports_missing_sample_rate() {
+        let result = TinySmoother::builder().half_life_ms(10.0).build();
+        assert_eq!(result.err(), Some(SmootherError::MissingSampleRate));
+    }
+
+    #[test]
+    fn builder_reports_conflicting_half_life() {
+        let result = TinySmoother::builder()
+            .sample_rate(48_000.0)
+            .half_life_ms(10.0)
+            .half_life_samples(480.0)
+            .build();
+        assert_eq!(result.err(), Some(SmootherError::ConflictingHalfLife));
+    }
+
+    #[test]
+    fn builder_reports_invalid_values() {
+        let result = TinySmoother::builder().sample_rate(0.0).build();
+        assert_eq!(result.err(), Some(SmootherError::InvalidSampleRate(0.0)));
+
+        let result = TinySmoother::builder().half_life_samples(-1.0).build();
+        assert_eq!(result.err(), Some(SmootherError::InvalidHalfLife(-1.0)));
+
+        let result = TinySmoother::builder().start_value(f32::INFINITY).build();
+        assert_eq!(result.err(), Some(SmootherError::InvalidStartValue(f32::INFINITY)));
+
+        let result = TinySmoother::builder().snap_threshold(-0.5).build();
+        assert_eq!(result.err(), Some(SmootherError::InvalidSnapThreshold(-0.5)));
+    }
+
+    #[test]
+    fn builder_errors_have_clear_messages() {
+        let message = SmootherError::MissingSampleRate.to_string();
+        assert_eq!(message, "Half-life in milliseconds requires a sample rate");
     }
 
     //--- Edge case tests
@@ -233,6 +2602,23 @@ mod tests {
         assert_eq!(smoother.next(-1.0), -1.0);
     }
 
+    #[test]
+    fn beta_zero_fast_path_matches_filter() {
+        let targets = [0.3, f32::NAN, 1e-40, -7.25, f32::INFINITY, 0.0, 1.0 / 3.0];
+        let mut fast = TinySmoother::new(0.0, 0.1);
+        let mut filtered = TinySmoother::new(0.0, 0.1);
+        for target in targets {
+            let expected = if target.is_finite() {
+                filtered.target = target as f64;
+                filtered.step() as f32
+            } else {
+                filtered.last_value as f32
+            };
+            assert_eq!(fast.next(target).to_bits(), expected.to_bits());
+            assert_eq!(fast.last_value, filtered.last_value);
+        }
+    }
+
     #[test]
     #[should_panic(expected = "Beta must be in range [0.0, 1.0)")]
     fn smoother_panics_on_beta_one() {
@@ -298,6 +2684,46 @@ mod tests {
         assert!(continued >= last_valid); // Should continue from where it was
     }
 
+    #[test]
+    fn bipolar_sweep_crosses_zero_smoothly() {
+        let beta = beta_from_half_life(100.0);
+        let mut pan = TinySmoother::new(beta, -1.0);
+        let mut last = -1.0f64;
+        let mut last_step = f64::NAN;
+        let mut crossed = false;
+        for n in 0..2000 {
+            let value = pan.next_f64(1.0);
+            let step = value - last;
+            // monotonic, and every step is beta times the previous one, also across zero
+            assert!(step > 0.0, "sample {n}");
+            if n > 0 {
+                assert!((step / last_step - beta).abs() < 1e-6, "sample {n}");
+            }
+            crossed |= last < 0.0 && value >= 0.0;
+            last = value;
+            last_step = step;
+        }
+        assert!(crossed);
+        // the zero crossing is at one half-life: half of the distance of 2.0
+        let mut pan = TinySmoother::new(beta, -1.0);
+        let at_half_life = (0..100).map(|_| pan.next_f64(1.0)).last().unwrap();
+        assert!(at_half_life.abs() < 1e-9, "{at_half_life}");
+    }
+
+    #[test]
+    fn bipolar_ramps_are_exact_mirror_images() {
+        for snap_threshold in [0.0, 1e-4] {
+            let mut rising = TinySmoother::new(0.995, -1.0);
+            let mut falling = TinySmoother::new(0.995, 1.0);
+            rising.set_snap_threshold(snap_threshold);
+            falling.set_snap_threshold(snap_threshold);
+            for n in 0..10_000 {
+                let target = if n < 5_000 { 1.0 } else { -0.25 };
+                assert_eq!(rising.next(target), -falling.next(-target), "sample {n}");
+            }
+        }
+    }
+
     #[test]
     fn smoother_reset_works_with_different_start_values() {
         // Test with a positive start value
@@ -0,0 +1,207 @@
+//! Verifies that the real-time APIs never allocate once their objects are constructed.
+//!
+//! A counting global allocator records every allocation while a check runs. Construction and
+//! input buffers are prepared before counting starts, only the per-sample and per-block calls
+//! are measured.
+//!
+//! This test uses its own harness (`harness = false`), so no test runner threads allocate
+//! concurrently while counting.
+
+use audio_utils::{
+    apply_smoothed_gain, db_to_volt, db_to_volt_f, db_to_volt_slice, hard_clip, peak_db, rms_db,
+    scale_block, snap_gain, soft_clip, volt_to_db, volt_to_db_f, volt_to_db_slice,
+    volt_to_db_slice_f, Adsr, ArraySmoother, AttackReleaseSmoother, Ballistics, BypassFade,
+    Cascade2Smoother, ClipIndicator, Crossfader, GainRamp, Log2Smoother, Meter, OneEuroSmoother,
+    PeakMeter, RampShape, RmsMeter, SmoothedGain, TinySmoother, TinySmootherF32,
+};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::process::ExitCode;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// Passes all requests on to the system allocator and counts them while `COUNTING` is set.
+struct CountingAllocator;
+
+static COUNTING: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if COUNTING.load(Ordering::SeqCst) {
+            ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        }
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        if COUNTING.load(Ordering::SeqCst) {
+            ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        }
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Number of samples per block
+const BLOCK: usize = 512;
+
+/// Runs `f` and returns the number of heap allocations it made.
+fn allocations(f: impl FnOnce()) -> usize {
+    ALLOCATIONS.store(0, Ordering::SeqCst);
+    COUNTING.store(true, Ordering::SeqCst);
+    f();
+    COUNTING.store(false, Ordering::SeqCst);
+    ALLOCATIONS.load(Ordering::SeqCst)
+}
+
+fn main() -> ExitCode {
+    // inputs including non-finite values, to cover the guards as well
+    let samples: Vec<f32> = (0..BLOCK)
+        .map(|n| match n % 97 {
+            0 => f32::NAN,
+            1 => f32::INFINITY,
+            _ => (n as f32 * 0.1).sin(),
+        })
+        .collect();
+    let dbs: Vec<i32> = (0..BLOCK as i32).map(|n| n % 160 - 120).collect();
+    let mut buffer = samples.clone();
+    let mut out = vec![0.0f32; BLOCK];
+    let mut db_out = vec![0i32; BLOCK];
+    let mut left = samples.clone();
+    let mut right = samples.clone();
+
+    let mut smoother = TinySmoother::default();
+    let mut smoother_f32 = TinySmootherF32::default();
+    let mut bands = ArraySmoother::<4>::default();
+    let mut cascade = Cascade2Smoother::default();
+    let mut smoothed_gain = SmoothedGain::default();
+    let mut attack_release = AttackReleaseSmoother::from_times(48_000.0, 1.0, 100.0, 0.0);
+    let mut one_euro = OneEuroSmoother::new(48_000.0, 1.0, 0.01, 1.0);
+    let mut log2 = Log2Smoother::new(48_000.0, 1.0, 440.0);
+    let mut ramp = GainRamp::new(RampShape::Linear);
+    let mut adsr = Adsr::new(48_000.0, 5.0, 50.0, 0.5, 200.0);
+    let mut crossfader = Crossfader::new(48_000.0, 10.0, 0.0);
+    let mut bypass = BypassFade::default();
+    let mut peak_meter = PeakMeter::default();
+    let mut rms_meter = RmsMeter::new(BLOCK);
+    let mut meter = Meter::new(48_000.0, Ballistics::Ppm);
+    let mut clip = ClipIndicator::new(0.0, Some(4800));
+
+    let checks: Vec<(&str, usize)> = vec![
+        (
+            "conversions",
+            allocations(|| {
+                for (&db, &sample) in dbs.iter().zip(&samples) {
+                    black_box(db_to_volt(db));
+                    black_box(db_to_volt_f(sample * 50.0));
+                    black_box(volt_to_db(sample));
+                    black_box(volt_to_db_f(sample));
+                    black_box(snap_gain(sample));
+                }
+                db_to_volt_slice(&dbs, &mut out);
+                volt_to_db_slice_f(&samples, &mut out);
+                volt_to_db_slice(&samples, &mut db_out);
+            }),
+        ),
+        (
+            "TinySmoother",
+            allocations(|| {
+                for &target in &samples {
+                    black_box(smoother.next(target));
+                    smoother.set_target(target);
+                    black_box(smoother.tick());
+                    black_box(smoother.blend(target, 0.0, 0.5));
+                    black_box(smoother.blend_equal_power(target, 0.0, 0.5));
+                }
+                black_box(smoother.skip(1.0, 1000));
+                for value in smoother.smooth_iter(&samples) {
+                    black_box(value);
+                }
+                smoother.process_frames(0.5, &mut [&mut left, &mut right]);
+            }),
+        ),
+        (
+            "buffer helpers",
+            allocations(|| {
+                apply_smoothed_gain(&mut smoother, 0.5, &mut buffer, 2);
+                black_box(smoother.apply_and_peak(1.0, &mut buffer, 2));
+                scale_block(&mut buffer, 0.5);
+                #[cfg(feature = "simd")]
+                audio_utils::apply_gain_simd(&mut buffer, 2.0);
+                for &sample in &samples {
+                    black_box(soft_clip(sample));
+                    black_box(hard_clip(sample, 0.5));
+                }
+            }),
+        ),
+        (
+            "smoothers",
+            allocations(|| {
+                smoothed_gain.set_db(-6.0);
+                for &sample in &samples {
+                    black_box(smoothed_gain.next_gain());
+                    black_box(smoother_f32.next(sample));
+                    black_box(bands.next([sample; 4]));
+                    black_box(cascade.next(sample));
+                    black_box(attack_release.next(sample.abs()));
+                    black_box(one_euro.next(sample));
+                    black_box(log2.next(sample * 1000.0));
+                }
+            }),
+        ),
+        (
+            "ramps and envelopes",
+            allocations(|| {
+                ramp.arm(0.0, 1.0, BLOCK as u32 / 2);
+                adsr.note_on();
+                for (n, &sample) in samples.iter().enumerate() {
+                    if n == BLOCK / 2 {
+                        adsr.note_off();
+                    }
+                    black_box(ramp.next());
+                    black_box(adsr.next());
+                    black_box(crossfader.gains(1.0));
+                    black_box(bypass.mix(sample, 0.0, n > BLOCK / 4));
+                }
+            }),
+        ),
+        (
+            "meters",
+            allocations(|| {
+                peak_meter.push_block(&samples);
+                rms_meter.push_block(&samples);
+                meter.push_block(&samples);
+                clip.push_block(&samples);
+                black_box(peak_meter.level_db());
+                black_box(rms_meter.level_db());
+                black_box(meter.read_db());
+                black_box(peak_db(&samples));
+                black_box(rms_db(&samples));
+            }),
+        ),
+    ];
+
+    let mut failed = 0;
+    for (name, count) in &checks {
+        if *count == 0 {
+            println!("test {name} ... ok");
+        } else {
+            failed += 1;
+            println!("test {name} ... FAILED, {count} allocations");
+        }
+    }
+    println!(
+        "\ntest result: {}. {} passed; {} failed",
+        if failed == 0 { "ok" } else { "FAILED" },
+        checks.len() - failed,
+        failed
+    );
+
+    if failed == 0 { ExitCode::SUCCESS } else { ExitCode::FAILURE }
+}
@@ -0,0 +1,141 @@
+//! Verifies that the hot-path functions never panic, across a fuzzed range of inputs
+//! including subnormals, infinities, `NaN` and extreme values.
+//!
+//! This test uses its own harness (`harness = false`): every call runs inside
+//! `catch_unwind`, so a single panicking input is reported without aborting the run.
+
+use audio_utils::{db_to_volt, db_to_volt_f, volt_to_db, volt_to_db_f, TinySmoother};
+use rand::{Rng, SeedableRng, rngs::SmallRng};
+use std::hint::black_box;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::process::ExitCode;
+
+/// Number of random inputs per function, in addition to the special values.
+const RANDOM_INPUTS: usize = 100_000;
+
+/// Special f32 values: zeros, subnormals, limits, infinities and NaN.
+fn special_f32() -> Vec<f32> {
+    let mut values = vec![
+        0.0,
+        -0.0,
+        f32::from_bits(1), // smallest subnormal
+        f32::MIN_POSITIVE / 2.0,
+        f32::MIN_POSITIVE,
+        f32::EPSILON,
+        1.0,
+        f32::MAX,
+        f32::MIN,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        f32::NAN,
+        -f32::NAN,
+    ];
+    let negated: Vec<f32> = values.iter().map(|value| -value).collect();
+    values.extend(negated);
+    values
+}
+
+/// Special values plus random bit patterns, which cover all exponents and NaN payloads.
+fn fuzzed_f32(rng: &mut SmallRng) -> Vec<f32> {
+    let mut values = special_f32();
+    values.extend((0..RANDOM_INPUTS).map(|_| f32::from_bits(rng.random())));
+    values
+}
+
+fn fuzzed_i32(rng: &mut SmallRng) -> Vec<i32> {
+    let mut values = vec![i32::MIN, i32::MIN + 1, -101, -100, 0, 27, 28, i32::MAX];
+    values.extend((0..RANDOM_INPUTS).map(|_| rng.random::<i32>()));
+    values
+}
+
+/// Runs `f` for every input and returns the inputs that panicked.
+fn panicking_inputs<T: Copy + std::fmt::Debug>(inputs: &[T], f: impl Fn(T)) -> Vec<String> {
+    inputs
+        .iter()
+        .filter(|&&input| catch_unwind(AssertUnwindSafe(|| f(input))).is_err())
+        .map(|input| format!("{input:?}"))
+        .collect()
+}
+
+fn main() -> ExitCode {
+    let mut rng = SmallRng::seed_from_u64(0xDEC1_BA5E_u64); // fixed seed
+    let floats = fuzzed_f32(&mut rng);
+    let integers = fuzzed_i32(&mut rng);
+    let betas = [0.0, 0.5, 0.999, 1.0 - f64::EPSILON];
+    let starts = special_f32()
+        .into_iter()
+        .filter(|value| value.is_finite())
+        .collect::<Vec<_>>();
+
+    let checks: Vec<(&str, Vec<String>)> = vec![
+        (
+            "db_to_volt",
+            panicking_inputs(&integers, |db| {
+                black_box(db_to_volt(db));
+            }),
+        ),
+        (
+            "db_to_volt_f",
+            panicking_inputs(&floats, |db| {
+                black_box(db_to_volt_f(db));
+            }),
+        ),
+        (
+            "volt_to_db",
+            panicking_inputs(&floats, |gain| {
+                black_box(volt_to_db(gain));
+            }),
+        ),
+        (
+            "volt_to_db_f",
+            panicking_inputs(&floats, |gain| {
+                black_box(volt_to_db_f(gain));
+            }),
+        ),
+        (
+            "TinySmoother::next",
+            panicking_inputs(&betas, |beta| {
+                for &start in &starts {
+                    let mut smoother = TinySmoother::new(beta, start);
+                    for &target in &floats {
+                        black_box(smoother.next(target));
+                    }
+                }
+            }),
+        ),
+        (
+            "TinySmoother::tick",
+            panicking_inputs(&betas, |beta| {
+                for &start in &starts {
+                    let mut smoother = TinySmoother::new(beta, start);
+                    for &target in &floats {
+                        smoother.set_target(target);
+                        black_box(smoother.tick());
+                    }
+                }
+            }),
+        ),
+    ];
+
+    let mut failed = 0;
+    for (name, panics) in &checks {
+        if panics.is_empty() {
+            println!("test {name} ... ok");
+        } else {
+            failed += 1;
+            println!(
+                "test {name} ... FAILED, panicked for {} inputs, e.g. {}",
+                panics.len(),
+                panics[0]
+            );
+        }
+    }
+    println!(
+        "\ntest result: {}. {} passed; {} failed",
+        if failed == 0 { "ok" } else { "FAILED" },
+        checks.len() - failed,
+        failed
+    );
+
+    if failed == 0 { ExitCode::SUCCESS } else { ExitCode::FAILURE }
+}